@@ -0,0 +1,297 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, LitStr, parse_macro_input};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Segment {
+    Literal(String),
+    Field { name: String, kind: FieldKind },
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum FieldKind {
+    // `{name}`: the whole capture is parsed with `FromStr`
+    Single,
+    // `{name:,}`: items separated by `,`
+    List(String),
+    // `{name:(,)*}`: any number of `(...)` groups, each a `,` list
+    Groups {
+        open: char,
+        separator: String,
+        close: char,
+    },
+}
+
+// Derives `FromStr` for a struct with named fields from a line format, e.g.
+//
+//     #[derive(AocParse)]
+//     #[aoc(format = "[{lights}] {buttons:(,)*} {{{joltage:,}}}")]
+//     struct Machine { lights: Lights, buttons: Vec<Vec<usize>>, joltage: Vec<u64> }
+//
+// `{{` and `}}` are literal braces, and whitespace in the format matches any
+// run of whitespace in the input. A field captures everything up to the next
+// literal text, so two fields must be separated by some literal.
+#[proc_macro_derive(AocParse, attributes(aoc))]
+pub fn derive_aoc_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "AocParse can only be derived for structs",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "AocParse needs a struct with named fields",
+        ));
+    };
+
+    let format = get_format(input)?;
+    let segments =
+        parse_format(&format.value()).map_err(|err| syn::Error::new_spanned(&format, err))?;
+
+    let struct_fields: Vec<String> = fields
+        .named
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(|ident| ident.to_string()))
+        .collect();
+    let format_fields: Vec<&String> = segments
+        .iter()
+        .filter_map(|segment| match segment {
+            Segment::Field { name, .. } => Some(name),
+            Segment::Literal(_) => None,
+        })
+        .collect();
+
+    for name in &format_fields {
+        if !struct_fields.contains(name) {
+            return Err(syn::Error::new_spanned(
+                &format,
+                format!("format refers to unknown field `{name}`"),
+            ));
+        }
+        if format_fields.iter().filter(|&other| other == name).count() > 1 {
+            return Err(syn::Error::new_spanned(
+                &format,
+                format!("field `{name}` appears more than once in the format"),
+            ));
+        }
+    }
+    for name in &struct_fields {
+        if !format_fields.contains(&name) {
+            return Err(syn::Error::new_spanned(
+                &format,
+                format!("field `{name}` is missing from the format"),
+            ));
+        }
+    }
+
+    let steps = segments.iter().enumerate().map(|(idx, segment)| match segment {
+        Segment::Literal(text) => quote! { __scanner.literal(#text)?; },
+        Segment::Field { name, kind } => {
+            let ident = format_ident!("{}", name);
+            let capture = match segments.get(idx + 1) {
+                Some(Segment::Literal(text)) => {
+                    let stop = text.split_whitespace().next().unwrap_or("");
+                    quote! { __scanner.take_until(#name, #stop)? }
+                }
+                _ => quote! { __scanner.take_rest() },
+            };
+            let value = match kind {
+                FieldKind::Single => {
+                    quote! { ::utils::line_format::parse_field(#name, #capture)? }
+                }
+                FieldKind::List(separator) => quote! {
+                    ::utils::line_format::parse_list(#name, #capture, #separator)?
+                },
+                FieldKind::Groups {
+                    open,
+                    separator,
+                    close,
+                } => quote! {
+                    ::utils::line_format::parse_groups(#name, #capture, #open, #separator, #close)?
+                },
+            };
+
+            quote! { let #ident = #value; }
+        }
+    });
+
+    let ident = &input.ident;
+    let field_idents = fields.named.iter().map(|field| &field.ident);
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::std::str::FromStr for #ident #ty_generics #where_clause {
+            type Err = String;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let mut __scanner = ::utils::line_format::LineScanner::new(s);
+                #(#steps)*
+                __scanner.finish()?;
+
+                Ok(Self { #(#field_idents),* })
+            }
+        }
+    })
+}
+
+fn get_format(input: &DeriveInput) -> syn::Result<LitStr> {
+    let mut format = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("aoc"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `format = \"...\"`"))
+            }
+        })?;
+    }
+
+    format.ok_or_else(|| {
+        syn::Error::new_spanned(&input.ident, "missing #[aoc(format = \"...\")] attribute")
+    })
+}
+
+fn parse_format(format: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = vec![];
+    let mut literal = String::new();
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut spec = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => spec.push(c),
+                        None => return Err(format!("unclosed `{{{spec}` in format")),
+                    }
+                }
+
+                if !literal.is_empty() {
+                    segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                } else if let Some(Segment::Field { name, .. }) = segments.last() {
+                    return Err(format!(
+                        "field `{name}` must be followed by literal text before the next field"
+                    ));
+                }
+                segments.push(parse_field_spec(&spec)?);
+            }
+            '}' => return Err("unmatched `}` in format, use `}}` for a literal brace".into()),
+            c => literal.push(c),
+        }
+    }
+
+    if !literal.is_empty() {
+        segments.push(Segment::Literal(literal));
+    }
+
+    Ok(segments)
+}
+
+fn parse_field_spec(spec: &str) -> Result<Segment, String> {
+    let (name, kind) = match spec.split_once(':') {
+        None => (spec, FieldKind::Single),
+        Some((name, list)) => {
+            let chars: Vec<char> = list.chars().collect();
+            let kind = match chars[..] {
+                [] => return Err(format!("field `{name}` has an empty separator")),
+                [open, ref separator @ .., close, '*'] if !separator.is_empty() => {
+                    FieldKind::Groups {
+                        open,
+                        separator: separator.iter().collect(),
+                        close,
+                    }
+                }
+                [.., '*'] => {
+                    return Err(format!(
+                        "field `{name}` repeats groups but is not of the form `(,)*`"
+                    ));
+                }
+                _ => FieldKind::List(list.to_string()),
+            };
+
+            (name, kind)
+        }
+    };
+
+    if name.is_empty() || !name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric()) {
+        return Err(format!("invalid field name `{name}` in format"));
+    }
+
+    Ok(Segment::Field {
+        name: name.to_string(),
+        kind,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn field(name: &str, kind: FieldKind) -> Segment {
+        Segment::Field {
+            name: name.to_string(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_parse_format() {
+        let segments = parse_format("[{lights}] {buttons:(,)*} {{{joltage:,}}}").unwrap();
+
+        assert_eq!(
+            vec![
+                Segment::Literal("[".to_string()),
+                field("lights", FieldKind::Single),
+                Segment::Literal("] ".to_string()),
+                field(
+                    "buttons",
+                    FieldKind::Groups {
+                        open: '(',
+                        separator: ",".to_string(),
+                        close: ')',
+                    }
+                ),
+                Segment::Literal(" {".to_string()),
+                field("joltage", FieldKind::List(",".to_string())),
+                Segment::Literal("}".to_string()),
+            ],
+            segments
+        );
+    }
+
+    #[test]
+    fn test_parse_format_errors() {
+        assert!(parse_format("{a}{b}").is_err());
+        assert!(parse_format("{a").is_err());
+        assert!(parse_format("a}").is_err());
+        assert!(parse_format("{a:}").is_err());
+        assert!(parse_format("{a:()*}").is_err());
+        assert!(parse_format("{a b}").is_err());
+    }
+}
@@ -0,0 +1,75 @@
+use benches::Bench;
+use utils::BitGrid;
+
+fn main() {
+    let mut bench = Bench::from_args();
+
+    bench.day(
+        1,
+        utils::get_lines,
+        |lines| one::part_one(lines),
+        |lines| one::part_two(lines),
+    );
+    bench.day(
+        2,
+        utils::get_int_ranges::<u64>,
+        |ranges| two::part_one(ranges),
+        |ranges| two::part_two(ranges),
+    );
+    bench.day(
+        3,
+        utils::get_lines,
+        |lines| three::part_one(lines),
+        |lines| three::part_two(lines),
+    );
+    // Part two removes rolls as it goes, so every iteration gets a fresh copy
+    bench.day(
+        4,
+        |input| BitGrid::from(&utils::get_binary_grid(input, '@').unwrap()),
+        four::part_one,
+        |grid| four::part_two(&mut grid.clone()),
+    );
+    bench.day(
+        5,
+        |input| five::parse_inventory(input).unwrap(),
+        five::part_one,
+        five::part_two,
+    );
+    bench.day(
+        6,
+        |input| {
+            (
+                utils::get_lines(input),
+                utils::get_raw_grid_padded(input, ' '),
+            )
+        },
+        |(lines, _)| six::part_one(lines),
+        |(_, grid)| six::part_two(grid),
+    );
+    bench.day(
+        7,
+        |input| utils::get_raw_grid(input).unwrap(),
+        seven::part_one,
+        seven::part_two,
+    );
+    bench.day(
+        8,
+        utils::get_lines,
+        |lines| eight::part_one(lines, 1000),
+        |lines| eight::part_two(lines),
+    );
+    bench.day(
+        9,
+        |input| nine::get_points(&utils::get_lines(input)).unwrap(),
+        |points| nine::part_one(points),
+        |points| nine::part_two(points),
+    );
+    bench.day(
+        10,
+        |input| ten::parse_machines(&utils::get_lines(input)).unwrap(),
+        |machines| ten::part_one(machines),
+        |machines| ten::part_two(machines),
+    );
+
+    bench.finish();
+}
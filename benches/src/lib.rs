@@ -0,0 +1,277 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+const WARM_UP: Duration = Duration::from_millis(300);
+const MEASUREMENT: Duration = Duration::from_secs(1);
+const MIN_SAMPLES: usize = 5;
+const MAX_SAMPLES: usize = 30;
+const DEFAULT_THRESHOLD: f64 = 10.0;
+
+// Per-iteration times of one benchmark, in nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+impl Stats {
+    fn from_samples(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2.0
+        } else {
+            samples[mid]
+        };
+
+        Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+struct Nanos(f64);
+
+impl fmt::Display for Nanos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            ns if ns < 1e3 => write!(f, "{ns:.2} ns"),
+            ns if ns < 1e6 => write!(f, "{:.2} µs", ns / 1e3),
+            ns if ns < 1e9 => write!(f, "{:.2} ms", ns / 1e6),
+            ns => write!(f, "{:.2} s", ns / 1e9),
+        }
+    }
+}
+
+// Median times by benchmark name, stored as `name nanoseconds` lines.
+// Lines starting with `#` are comments.
+pub fn parse_baselines(s: &str) -> BTreeMap<String, f64> {
+    s.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(|line| {
+            let (name, nanos) = line.split_once(char::is_whitespace)?;
+            Some((name.to_string(), nanos.trim().parse().ok()?))
+        })
+        .collect()
+}
+
+fn render_baselines(baselines: &BTreeMap<String, f64>) -> String {
+    let mut out = String::from(
+        "# Median time per iteration in nanoseconds, from `cargo bench -p benches -- --save-baseline`\n",
+    );
+    for (name, nanos) in baselines {
+        out.push_str(&format!("{name} {nanos:.0}\n"));
+    }
+
+    out
+}
+
+// Percentage change from `baseline` to `median`, and whether it is beyond
+// `threshold` percent in either direction.
+pub fn compare(baseline: f64, median: f64, threshold: f64) -> (f64, Option<&'static str>) {
+    let change = (median - baseline) / baseline * 100.0;
+    let verdict = if change > threshold {
+        Some("regressed")
+    } else if change < -threshold {
+        Some("improved")
+    } else {
+        None
+    };
+
+    (change, verdict)
+}
+
+// Runs benchmarks criterion-style and compares their medians against the
+// baselines checked in next to this crate:
+//
+//     cargo bench -p benches                          # compare
+//     cargo bench -p benches -- day9                  # only names containing `day9`
+//     cargo bench -p benches -- --save-baseline       # record new baselines
+//     cargo bench -p benches -- --threshold 5         # flag changes beyond 5%
+pub struct Bench {
+    filter: Option<String>,
+    save_baseline: bool,
+    threshold: f64,
+    baselines_path: PathBuf,
+    baselines: BTreeMap<String, f64>,
+    regressions: Vec<String>,
+}
+
+impl Bench {
+    pub fn from_args() -> Self {
+        let mut filter = None;
+        let mut save_baseline = false;
+        let mut threshold = DEFAULT_THRESHOLD;
+
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                // Passed by `cargo bench` itself
+                "--bench" => {}
+                "--save-baseline" => save_baseline = true,
+                "--threshold" => {
+                    threshold = args
+                        .next()
+                        .and_then(|t| t.parse().ok())
+                        .expect("--threshold needs a percentage")
+                }
+                other if !other.starts_with('-') => filter = Some(other.to_string()),
+                other => panic!("Unknown argument: {other}"),
+            }
+        }
+
+        let baselines_path = Path::new(env!("CARGO_MANIFEST_DIR")).join("baselines.txt");
+        let baselines = fs::read_to_string(&baselines_path)
+            .map(|s| parse_baselines(&s))
+            .unwrap_or_default();
+
+        Self {
+            filter,
+            save_baseline,
+            threshold,
+            baselines_path,
+            baselines,
+            regressions: vec![],
+        }
+    }
+
+    fn enabled(&self, name: &str) -> bool {
+        self.filter
+            .as_ref()
+            .is_none_or(|filter| name.contains(filter))
+    }
+
+    // Benchmarks parsing `inputs/dayN.txt` and both parts on the parsed
+    // input. Days whose input is missing are skipped.
+    pub fn day<P, A, B>(
+        &mut self,
+        day: usize,
+        parse: impl Fn(&str) -> P,
+        part_one: impl Fn(&P) -> A,
+        part_two: impl Fn(&P) -> B,
+    ) {
+        let names = ["parse", "part_one", "part_two"].map(|stage| format!("day{day}/{stage}"));
+        if !names.iter().any(|name| self.enabled(name)) {
+            return;
+        }
+
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../inputs")
+            .join(format!("day{day}.txt"));
+        let Ok(input) = utils::read_file(&path) else {
+            println!("day{day}: skipped, no input at {}", path.display());
+            return;
+        };
+
+        self.run(&names[0], || parse(&input));
+        let parsed = parse(&input);
+        self.run(&names[1], || part_one(&parsed));
+        self.run(&names[2], || part_two(&parsed));
+    }
+
+    pub fn run<T>(&mut self, name: &str, mut f: impl FnMut() -> T) {
+        if !self.enabled(name) {
+            return;
+        }
+
+        // Warm up, which also estimates how long one iteration takes
+        let start = Instant::now();
+        let mut warm_up_iters = 0u32;
+        while warm_up_iters == 0 || start.elapsed() < WARM_UP {
+            black_box(f());
+            warm_up_iters += 1;
+        }
+        let estimate = start.elapsed().as_secs_f64() / warm_up_iters as f64;
+
+        let budget = MEASUREMENT.as_secs_f64();
+        let samples = ((budget / estimate) as usize).clamp(MIN_SAMPLES, MAX_SAMPLES);
+        let iters = ((budget / samples as f64 / estimate) as u32).max(1);
+
+        let samples: Vec<f64> = (0..samples)
+            .map(|_| {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    black_box(f());
+                }
+                start.elapsed().as_nanos() as f64 / iters as f64
+            })
+            .collect();
+        let stats = Stats::from_samples(samples);
+
+        print!(
+            "{name:<16} time: [{} {} {}]",
+            Nanos(stats.min),
+            Nanos(stats.median),
+            Nanos(stats.max)
+        );
+        match self.baselines.get(name) {
+            Some(&baseline) if !self.save_baseline => {
+                let (change, verdict) = compare(baseline, stats.median, self.threshold);
+                print!("  change: {change:+.1}% vs {}", Nanos(baseline));
+                if let Some(verdict) = verdict {
+                    print!(" ({verdict})");
+                    if verdict == "regressed" {
+                        self.regressions.push(name.to_string());
+                    }
+                }
+            }
+            None if !self.save_baseline => print!("  (no baseline)"),
+            _ => {}
+        }
+        println!();
+
+        if self.save_baseline {
+            self.baselines.insert(name.to_string(), stats.median);
+        }
+    }
+
+    pub fn finish(self) {
+        if self.save_baseline {
+            fs::write(&self.baselines_path, render_baselines(&self.baselines))
+                .expect("Failed to write baselines");
+            println!("Saved baselines to {}", self.baselines_path.display());
+        } else if self.regressions.is_empty() {
+            println!("No regressions beyond {}%", self.threshold);
+        } else {
+            println!(
+                "{} regression(s) beyond {}%: {}",
+                self.regressions.len(),
+                self.threshold,
+                self.regressions.join(", ")
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_baselines_round_trip() {
+        let baselines =
+            parse_baselines("# comment\nday1/parse 1200\n\nday9/part_two  4.5e7\nbad\n");
+
+        assert_eq!(Some(&1200.0), baselines.get("day1/parse"));
+        assert_eq!(Some(&4.5e7), baselines.get("day9/part_two"));
+        assert_eq!(2, baselines.len());
+        assert_eq!(baselines, parse_baselines(&render_baselines(&baselines)));
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!((25.0, Some("regressed")), compare(100.0, 125.0, 10.0));
+        assert_eq!((-50.0, Some("improved")), compare(100.0, 50.0, 10.0));
+        assert_eq!((5.0, None), compare(100.0, 105.0, 10.0));
+        assert_eq!(3.0, Stats::from_samples(vec![4.0, 1.0, 2.0, 9.0]).median);
+    }
+}
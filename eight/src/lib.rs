@@ -0,0 +1,123 @@
+use utils::{Point3, UnionFind};
+
+#[derive(Debug)]
+struct Edge {
+    weight: i64,
+    idx_a: usize,
+    idx_b: usize,
+}
+
+// Keeps squared distances between boxes within an `i64`
+const MAX_COORDINATE: i64 = 1 << 29;
+
+pub fn get_points(lines: &[String]) -> Result<Vec<Point3<i64>>, String> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let point: Point3<i64> = line
+                .parse()
+                .map_err(|err| format!("Line {}: {err}", idx + 1))?;
+            if [point.x, point.y, point.z]
+                .iter()
+                .any(|c| !(-MAX_COORDINATE..=MAX_COORDINATE).contains(c))
+            {
+                return Err(format!(
+                    "Line {}: coordinates must be within {MAX_COORDINATE}, found {point}",
+                    idx + 1
+                ));
+            }
+
+            Ok(point)
+        })
+        .collect()
+}
+
+fn get_edges(points: &[Point3<i64>]) -> Vec<Edge> {
+    let n = points.len();
+    let mut edges = vec![];
+
+    for i in 0..n {
+        for j in i + 1..n {
+            edges.push(Edge {
+                weight: points[i].euclidean_sq(&points[j]),
+                idx_a: i,
+                idx_b: j,
+            });
+        }
+    }
+
+    edges.sort_unstable_by_key(|e| e.weight);
+    edges
+}
+
+pub fn part_two(lines: &[String]) -> Result<i64, String> {
+    let points = get_points(lines)?;
+    let edges = get_edges(&points);
+    let mut uf = UnionFind::new(points.len());
+
+    for edge in &edges {
+        uf.union(edge.idx_a, edge.idx_b);
+
+        if uf.num_parts() == 1 {
+            let xa = points[edge.idx_a].x;
+            let xb = points[edge.idx_b].x;
+            return Ok(xa * xb);
+        }
+    }
+    Ok(0)
+}
+
+// Fewer than three circuits multiply as many as there are
+pub fn part_one(lines: &[String], max_conns: usize) -> Result<usize, String> {
+    let points = get_points(lines)?;
+    let edges = get_edges(&points);
+    let mut uf = UnionFind::new(points.len());
+
+    let mut connections = 0;
+    for edge in &edges {
+        uf.union(edge.idx_a, edge.idx_b);
+        connections += 1;
+
+        if connections == max_conns {
+            break;
+        }
+    }
+
+    let mut sizes = uf.all_sizes();
+    sizes.sort_unstable();
+
+    Ok(sizes.iter().rev().take(3).product())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 8,
+        parse: |input| utils::get_lines(input),
+        part_one: |lines| part_one(lines, 10).unwrap(),
+        part_two: |lines| part_two(lines).unwrap(),
+    }
+
+    #[test]
+    fn test_few_boxes() {
+        let lines = utils::get_lines("1,2,3\n4,5,6");
+
+        assert_eq!(Ok(2), part_one(&lines, 10));
+        assert_eq!(Ok(1), part_one(&[], 10));
+        assert_eq!(Ok(4), part_two(&lines));
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let lines = utils::get_lines("1,2,3\n4,5");
+        let err = Err("Line 2: expected 3 coordinates, got 2: 4,5".to_string());
+        assert_eq!(err, part_one(&lines, 10));
+
+        let lines = utils::get_lines("1,2,3\n1,-9223372036854775808,3");
+        let err = part_two(&lines).unwrap_err();
+        assert!(err.starts_with("Line 2: coordinates must be within "));
+    }
+}
@@ -0,0 +1,119 @@
+use utils::Sections;
+use utils::parse::{self, map, number, pair, tag};
+
+#[cfg(test)]
+mod reference;
+
+pub struct Inventory {
+    ranges: Vec<(usize, usize)>,
+    ids: Vec<usize>,
+}
+
+// Ranges must not run backwards
+fn get_tuple(s: &str) -> Option<(usize, usize)> {
+    let range = pair(number(), map(pair(tag("-"), number()), |(_, high)| high));
+
+    parse::parse_all(range, s).ok().filter(|(low, high)| low <= high)
+}
+
+pub fn parse_inventory(s: &str) -> Result<Inventory, String> {
+    let mut sections = Sections::new(s);
+    let ranges = sections.next_with(|section| utils::parse_lines_with(section, get_tuple))?;
+    let ids = sections.next_with(utils::parse_lines)?;
+
+    Ok(Inventory { ranges, ids })
+}
+
+fn merge_ranges(mut ranges: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    if ranges.is_empty() {
+        return vec![];
+    }
+
+    ranges.sort_by_key(|range| range.0);
+    let mut merged = vec![];
+    let mut current = ranges[0];
+
+    for range in ranges.into_iter().skip(1) {
+        if range.0 <= current.1 {
+            current.1 = current.1.max(range.1);
+        } else {
+            merged.push(current);
+            current = range;
+        }
+    }
+
+    merged.push(current);
+
+    merged
+}
+
+// Ranges covering all of `usize` have more fresh IDs than a `u64` holds
+pub fn part_two(inventory: &Inventory) -> Result<u64, String> {
+    let ranges = merge_ranges(inventory.ranges.clone());
+
+    let mut valid = 0u64;
+    for (low, high) in ranges {
+        valid = (high - low)
+            .checked_add(1)
+            .and_then(|len| valid.checked_add(len as u64))
+            .ok_or("Too many fresh IDs to count")?;
+    }
+
+    Ok(valid)
+}
+
+pub fn part_one(inventory: &Inventory) -> u64 {
+    let mut valid = 0;
+
+    for &id in &inventory.ids {
+        'check: for &(low, high) in &inventory.ranges {
+            if id >= low && id <= high {
+                valid += 1;
+                break 'check;
+            }
+        }
+    }
+
+    valid
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 5,
+        parse: |input| parse_inventory(input).unwrap(),
+        part_one: |inventory| part_one(inventory),
+        part_two: |inventory| part_two(inventory).unwrap(),
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(5, 100, (30, 1000), true, |input| {
+            let inventory = parse_inventory(input).ok()?;
+            let fast = (part_one(&inventory), part_two(&inventory).unwrap());
+            let slow = (
+                reference::part_one(&inventory),
+                reference::part_two(&inventory),
+            );
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_reversed_range() {
+        let err = parse_inventory("3-5\n14-10\n\n1\n").err();
+
+        assert_eq!(Some("Section 1: Line 2: invalid value: 14-10".to_string()), err);
+    }
+
+    #[test]
+    fn test_too_many_fresh_ids() {
+        let inventory = parse_inventory("0-18446744073709551615\n\n1").unwrap();
+
+        assert_eq!(1, part_one(&inventory));
+        assert_eq!(Err("Too many fresh IDs to count".to_string()), part_two(&inventory));
+    }
+}
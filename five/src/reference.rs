@@ -0,0 +1,27 @@
+use std::collections::HashSet;
+
+use crate::Inventory;
+
+pub fn part_one(inventory: &Inventory) -> u64 {
+    inventory
+        .ids
+        .iter()
+        .filter(|&&id| {
+            inventory
+                .ranges
+                .iter()
+                .any(|&(low, high)| (low..=high).contains(&id))
+        })
+        .count() as u64
+}
+
+// Collects every fresh ID, so ranges must stay small.
+pub fn part_two(inventory: &Inventory) -> u64 {
+    let fresh: HashSet<usize> = inventory
+        .ranges
+        .iter()
+        .flat_map(|&(low, high)| low..=high)
+        .collect();
+
+    fresh.len() as u64
+}
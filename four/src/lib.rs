@@ -0,0 +1,50 @@
+use utils::BitGrid;
+
+#[cfg(test)]
+mod reference;
+
+// Rolls of paper with fewer than four occupied neighbours
+fn get_removable(grid: &BitGrid) -> BitGrid {
+    grid.and(&grid.neighbour_counts().less_than(4))
+}
+
+pub fn part_two(grid: &mut BitGrid) -> u64 {
+    let mut sum = 0;
+    loop {
+        let removable = get_removable(grid);
+        if removable.is_empty() {
+            break;
+        }
+        sum += removable.count_ones() as u64;
+        *grid = grid.and_not(&removable);
+    }
+    sum
+}
+
+pub fn part_one(grid: &BitGrid) -> u64 {
+    get_removable(grid).count_ones() as u64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 4,
+        parse: |input| BitGrid::from(&utils::get_binary_grid(input, '@').unwrap()),
+        part_one: |grid| part_one(grid),
+        part_two: |grid| part_two(grid),
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(4, 50, (12, 12), true, |input| {
+            let lines = utils::get_lines(input);
+            let mut grid = BitGrid::from(&utils::get_binary_grid(input, '@').ok()?);
+            let fast = (part_one(&grid), part_two(&mut grid));
+            let slow = (reference::part_one(&lines), reference::part_two(&lines));
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+}
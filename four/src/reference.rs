@@ -0,0 +1,59 @@
+fn get_rolls(lines: &[String]) -> Vec<Vec<bool>> {
+    lines
+        .iter()
+        .map(|line| line.chars().map(|c| c == '@').collect())
+        .collect()
+}
+
+// Whether the roll at `(row, col)` has fewer than four rolls among its eight
+// neighbours
+fn removable(rolls: &[Vec<bool>], row: usize, col: usize) -> bool {
+    if !rolls[row][col] {
+        return false;
+    }
+
+    let mut neighbours = 0;
+    for r in row.saturating_sub(1)..=row + 1 {
+        for c in col.saturating_sub(1)..=col + 1 {
+            let roll = rolls.get(r).and_then(|cells| cells.get(c));
+            if (r, c) != (row, col) && roll == Some(&true) {
+                neighbours += 1;
+            }
+        }
+    }
+
+    neighbours < 4
+}
+
+pub fn part_one(lines: &[String]) -> u64 {
+    let rolls = get_rolls(lines);
+    let mut count = 0;
+    for row in 0..rolls.len() {
+        for col in 0..rolls[row].len() {
+            if removable(&rolls, row, col) {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+// Removes one roll at a time until none can be removed
+pub fn part_two(lines: &[String]) -> u64 {
+    let mut rolls = get_rolls(lines);
+    let mut count = 0;
+    'remove: loop {
+        for row in 0..rolls.len() {
+            for col in 0..rolls[row].len() {
+                if removable(&rolls, row, col) {
+                    rolls[row][col] = false;
+                    count += 1;
+                    continue 'remove;
+                }
+            }
+        }
+
+        return count;
+    }
+}
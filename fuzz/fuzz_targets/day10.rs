@@ -0,0 +1,20 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Part two tries every subset of buttons, so machines are kept to a few short
+// lines, which leaves room for about ten buttons each
+const MAX_MACHINES: usize = 10;
+const MAX_LINE_LEN: usize = 50;
+
+fuzz_target!(|input: &str| {
+    let lines = utils::get_lines(input);
+    if lines.len() > MAX_MACHINES || lines.iter().any(|line| line.len() > MAX_LINE_LEN) {
+        return;
+    }
+
+    if let Ok(machines) = ten::parse_machines(&lines) {
+        let _ = ten::part_one(&machines);
+        let _ = ten::part_two(&machines);
+    }
+});
@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Both parts walk every ID of every range, so inputs with many IDs are skipped
+const MAX_IDS: u64 = 10_000;
+
+fuzz_target!(|input: &str| {
+    let ranges = utils::get_int_ranges::<u64>(input);
+    let ids = ranges.iter().fold(0u64, |ids, &(low, high)| {
+        ids.saturating_add(high.saturating_sub(low))
+    });
+    if ids > MAX_IDS {
+        return;
+    }
+
+    let _ = two::part_one(&ranges);
+    let _ = two::part_two(&ranges);
+});
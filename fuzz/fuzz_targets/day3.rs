@@ -0,0 +1,9 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let lines = utils::get_lines(input);
+    let _ = three::part_one(&lines);
+    let _ = three::part_two(&lines);
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::BitGrid;
+
+fuzz_target!(|input: &str| {
+    if let Ok(grid) = utils::get_binary_grid(input, '@') {
+        let mut grid = BitGrid::from(&grid);
+        let _ = four::part_one(&grid);
+        let _ = four::part_two(&mut grid);
+    }
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(inventory) = five::parse_inventory(input) {
+        let _ = five::part_one(&inventory);
+        let _ = five::part_two(&inventory);
+    }
+});
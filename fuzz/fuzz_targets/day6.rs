@@ -0,0 +1,8 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = six::part_one(&utils::get_lines(input));
+    let _ = six::part_two(&utils::get_raw_grid_padded(input, ' '));
+});
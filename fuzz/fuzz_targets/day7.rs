@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(grid) = utils::get_raw_grid(input) {
+        let _ = seven::part_one(&grid);
+        let _ = seven::part_two(&grid);
+    }
+});
@@ -0,0 +1,16 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Both parts compare every pair of junction boxes, so inputs are kept short
+const MAX_BOXES: usize = 200;
+
+fuzz_target!(|input: &str| {
+    let lines = utils::get_lines(input);
+    if lines.len() > MAX_BOXES {
+        return;
+    }
+
+    let _ = eight::part_one(&lines, 1000);
+    let _ = eight::part_two(&lines);
+});
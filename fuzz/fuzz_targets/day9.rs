@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Both parts compare every pair of corners, so inputs are kept short
+const MAX_CORNERS: usize = 200;
+
+fuzz_target!(|input: &str| {
+    let lines = utils::get_lines(input);
+    if lines.len() > MAX_CORNERS {
+        return;
+    }
+
+    if let Ok(points) = nine::get_points(&lines) {
+        let _ = nine::part_one(&points);
+        let _ = nine::part_two(&points);
+    }
+});
@@ -0,0 +1,28 @@
+#![no_main]
+
+use std::io::{Cursor, Read};
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(mut reader) = utils::decompress(Box::new(Cursor::new(data.to_vec()))) {
+        let _ = reader.read_to_end(&mut vec![]);
+    }
+
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    let _ = utils::get_lines(input);
+    let _ = utils::sections(input);
+    let _ = utils::get_uints(input);
+    let _ = utils::get_ints::<i64>(input);
+    let _ = utils::get_int_ranges::<i64>(input);
+    let _ = utils::get_raw_grid(input);
+    let _ = utils::get_binary_grid(input, '#');
+    let _ = utils::get_raw_grid_padded(input, ' ');
+
+    let mut sections = utils::Sections::new(input);
+    while sections.remaining() > 0 {
+        let _ = sections.next_with(utils::parse_lines::<u64>);
+    }
+});
@@ -0,0 +1,219 @@
+use utils::{Compression, Connectivity, Grid, Point2, PrefixSum2D};
+
+#[cfg(test)]
+mod reference;
+
+// Keeps the area of any rectangle within an `i64`
+const MAX_COORDINATE: i64 = 1 << 30;
+
+pub fn get_points(lines: &[String]) -> Result<Vec<Point2<i64>>, String> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| {
+            let point: Point2<i64> = line
+                .parse()
+                .map_err(|err| format!("Line {}: {err}", idx + 1))?;
+            if [point.x, point.y]
+                .iter()
+                .any(|c| !(-MAX_COORDINATE..=MAX_COORDINATE).contains(c))
+            {
+                return Err(format!(
+                    "Line {}: coordinates must be within {MAX_COORDINATE}, found {point}",
+                    idx + 1
+                ));
+            }
+
+            Ok(point)
+        })
+        .collect()
+}
+
+// Whether the cell at `idx` of the padded grid is the gap between two
+// neighbouring integers. It holds no tiles, so it never rules out a rectangle
+// even when it lies outside.
+fn is_empty_gap(compression: &Compression<i64>, idx: usize) -> bool {
+    let gapped = idx.wrapping_sub(1);
+    let before = compression.value(gapped / 2);
+    let after = compression.value(gapped / 2 + 1);
+
+    gapped % 2 == 1
+        && before
+            .zip(after)
+            .is_some_and(|(before, after)| after - before == 1)
+}
+
+fn get_outside_grid(
+    points: &[Point2<i64>],
+    xs: &Compression<i64>,
+    ys: &Compression<i64>,
+) -> Option<Grid<u8>> {
+    // Pad the compressed grid by one cell on each side so every outside cell
+    // is connected to the top-left corner.
+    let rows = ys.gapped_len() + 2;
+    let cols = xs.gapped_len() + 2;
+    let mut boundary = Grid::fill(rows, cols, 0u8);
+    let n = points.len();
+
+    for i in 0..n {
+        let p1 = points[i];
+        let p2 = points[(i + 1) % n];
+
+        let (c1, c2) = (xs.gapped_index_of(p1.x)? + 1, xs.gapped_index_of(p2.x)? + 1);
+        let (r1, r2) = (ys.gapped_index_of(p1.y)? + 1, ys.gapped_index_of(p2.y)? + 1);
+
+        for row in r1.min(r2)..=r1.max(r2) {
+            for col in c1.min(c2)..=c1.max(c2) {
+                boundary.set(row, col, 1);
+            }
+        }
+    }
+
+    let labels = boundary
+        .label_regions(Connectivity::Four, |a, b| a == b)
+        .labels;
+    let outside_label = labels[(0, 0)];
+    let outside = labels
+        .enumerate_row_major()
+        .map(|((row, col), &label)| {
+            let empty = is_empty_gap(ys, row) || is_empty_gap(xs, col);
+            u8::from(label == outside_label && !empty)
+        })
+        .collect();
+
+    Some(Grid::new(outside, rows))
+}
+
+fn get_area(a: &Point2<i64>, b: &Point2<i64>) -> i64 {
+    ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
+}
+
+pub fn part_two(points: &[Point2<i64>]) -> u64 {
+    let xs = Compression::new(points.iter().map(|p| p.x));
+    let ys = Compression::new(points.iter().map(|p| p.y));
+    let outside = match get_outside_grid(points, &xs, &ys) {
+        Some(grid) => PrefixSum2D::new(&grid),
+        None => return 0,
+    };
+
+    let compressed: Vec<(usize, usize)> = points
+        .iter()
+        .filter_map(|p| xs.gapped_index_of(p.x).zip(ys.gapped_index_of(p.y)))
+        .map(|(col, row)| (col + 1, row + 1))
+        .collect();
+
+    let mut max_area = 0;
+    let n = points.len();
+    for i in 0..n {
+        for j in i + 1..n {
+            let area = get_area(&points[i], &points[j]);
+            if area <= max_area {
+                continue;
+            }
+
+            let (ci, ri) = compressed[i];
+            let (cj, rj) = compressed[j];
+            let rows = (ri.min(rj), ri.max(rj));
+            let cols = (ci.min(cj), ci.max(cj));
+
+            if outside.sum(rows, cols) == Some(0) {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area as u64
+}
+
+pub fn part_one(points: &[Point2<i64>]) -> u64 {
+    let mut max_area = 0;
+    for i in 0..points.len() {
+        for j in i + 1..points.len() {
+            let area = get_area(&points[i], &points[j]);
+
+            if area > max_area {
+                max_area = area;
+            }
+        }
+    }
+
+    max_area as u64
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 9,
+        parse: |input| get_points(&utils::get_lines(input)).unwrap(),
+        part_one: |points| part_one(points),
+        part_two: |points| part_two(points),
+    }
+
+    #[test]
+    fn test_negative_coordinates() {
+        let puzzle_input = r#"-3,-9
+        1,-9
+        1,-3
+        -1,-3
+        -1,-5
+        -8,-5
+        -8,-7
+        -3,-7"#;
+        let points = get_points(&utils::get_lines(puzzle_input)).unwrap();
+
+        assert_eq!(50, part_one(&points));
+        assert_eq!(24, part_two(&points));
+    }
+
+    // The edges at x = 12 and x = 13 leave no tiles between them, so the
+    // rectangle from 6,5 to 15,10 is made only of red and green tiles
+    #[test]
+    fn test_adjacent_edges() {
+        let puzzle_input = r#"4,9
+        6,9
+        6,10
+        12,10
+        12,6
+        13,6
+        13,13
+        15,13
+        15,5
+        13,5
+        13,4
+        12,4
+        12,5
+        6,5
+        6,8
+        4,8"#;
+        let points = get_points(&utils::get_lines(puzzle_input)).unwrap();
+
+        assert_eq!(60, part_two(&points));
+    }
+
+    // Dropping corners would leave a shape that isn't rectilinear, so failing
+    // polygons only shrink by regenerating them smaller
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(9, 50, (16, 20), false, |input| {
+            let points = get_points(&utils::get_lines(input)).ok()?;
+            let fast = (part_one(&points), part_two(&points));
+            let slow = (reference::part_one(&points), reference::part_two(&points));
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_invalid_points() {
+        let err = get_points(&utils::get_lines("1,2\n3")).unwrap_err();
+        assert_eq!("Line 2: expected 2 coordinates, got 1: 3", err);
+
+        let err = get_points(&utils::get_lines("1,2\n3,x")).unwrap_err();
+        assert_eq!("Line 2: column 3: expected a number", err);
+
+        let err = get_points(&utils::get_lines("-9223372036854775808,0")).unwrap_err();
+        assert!(err.starts_with("Line 1: coordinates must be within "));
+    }
+}
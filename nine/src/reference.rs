@@ -0,0 +1,49 @@
+use utils::Point2;
+
+fn get_area(a: &Point2<i64>, b: &Point2<i64>) -> u64 {
+    (a.x.abs_diff(b.x) + 1) * (a.y.abs_diff(b.y) + 1)
+}
+
+fn edges(points: &[Point2<i64>]) -> impl Iterator<Item = (Point2<i64>, Point2<i64>)> + '_ {
+    (0..points.len()).map(|i| (points[i], points[(i + 1) % points.len()]))
+}
+
+// Whether the tile is on the outline or inside it, by casting a ray to the
+// right and counting the vertical edges it crosses
+fn inside(points: &[Point2<i64>], x: i64, y: i64) -> bool {
+    let on_outline = edges(points).any(|(a, b)| {
+        (a.x.min(b.x)..=a.x.max(b.x)).contains(&x) && (a.y.min(b.y)..=a.y.max(b.y)).contains(&y)
+    });
+    let crossings = edges(points)
+        .filter(|(a, b)| a.x == b.x && a.x > x && (a.y.min(b.y)..a.y.max(b.y)).contains(&y))
+        .count();
+
+    on_outline || crossings % 2 == 1
+}
+
+pub fn part_one(points: &[Point2<i64>]) -> u64 {
+    let mut max_area = 0;
+    for a in points {
+        for b in points {
+            max_area = max_area.max(get_area(a, b));
+        }
+    }
+
+    max_area
+}
+
+// Checks every tile of every rectangle, so coordinates must stay small.
+pub fn part_two(points: &[Point2<i64>]) -> u64 {
+    let mut max_area = 0;
+    for a in points {
+        for b in points {
+            let covered = (a.x.min(b.x)..=a.x.max(b.x))
+                .all(|x| (a.y.min(b.y)..=a.y.max(b.y)).all(|y| inside(points, x, y)));
+            if covered {
+                max_area = max_area.max(get_area(a, b));
+            }
+        }
+    }
+
+    max_area
+}
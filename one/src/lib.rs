@@ -0,0 +1,66 @@
+use utils::parse::{self, alt, map, number, pair, tag};
+use utils::{Dial, DialStep, Direction, Rotation};
+
+#[cfg(test)]
+mod reference;
+
+fn rotation<'a>() -> impl parse::Parser<'a, Rotation> {
+    let direction = alt((
+        map(tag("L"), |_| Direction::Left),
+        map(tag("R"), |_| Direction::Right),
+    ));
+
+    map(pair(direction, number()), |(direction, clicks)| Rotation {
+        direction,
+        clicks,
+    })
+}
+
+fn parse_rotation(line: &str) -> Option<Rotation> {
+    parse::parse_all(rotation(), line).ok()
+}
+
+fn get_trace(lines: &[String]) -> Option<Vec<DialStep>> {
+    let rotations: Vec<Rotation> = lines
+        .iter()
+        .map(|line| parse_rotation(line))
+        .collect::<Option<_>>()?;
+    let mut dial = Dial::new(100, 50, 0)?;
+
+    Some(dial.trace(&rotations))
+}
+
+pub fn part_one(lines: &[String]) -> Option<u64> {
+    let trace = get_trace(lines)?;
+
+    Some(trace.iter().filter(|step| step.stopped_on_target).count() as u64)
+}
+
+pub fn part_two(lines: &[String]) -> Option<u64> {
+    let trace = get_trace(lines)?;
+
+    Some(trace.iter().map(|step| step.passes).sum())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 1,
+        parse: |input| utils::get_lines(input),
+        part_one: |lines| part_one(lines).unwrap(),
+        part_two: |lines| part_two(lines).unwrap(),
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(1, 100, (50, 300), true, |input| {
+            let lines = utils::get_lines(input);
+            let fast = (part_one(&lines)?, part_two(&lines)?);
+            let slow = (reference::part_one(&lines)?, reference::part_two(&lines)?);
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+// Turns the dial one click at a time, counting the rotations that stop on
+// zero and the clicks that land on it.
+fn simulate(lines: &[String]) -> Option<(u64, u64)> {
+    let mut position: i64 = 50;
+    let (mut stops, mut passes) = (0, 0);
+    for line in lines {
+        let step = match line.chars().next()? {
+            'L' => -1,
+            'R' => 1,
+            _ => return None,
+        };
+        let clicks: u64 = line[1..].parse().ok()?;
+        for _ in 0..clicks {
+            position = (position + step).rem_euclid(100);
+            if position == 0 {
+                passes += 1;
+            }
+        }
+        if position == 0 {
+            stops += 1;
+        }
+    }
+
+    Some((stops, passes))
+}
+
+pub fn part_one(lines: &[String]) -> Option<u64> {
+    simulate(lines).map(|(stops, _)| stops)
+}
+
+pub fn part_two(lines: &[String]) -> Option<u64> {
+    simulate(lines).map(|(_, passes)| passes)
+}
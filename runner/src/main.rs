@@ -0,0 +1,233 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+use utils::{BitGrid, Format, Report};
+
+const DAYS: u32 = 10;
+
+// Runs several days in one go, e.g. for dashboards:
+//
+//     cargo run -p runner -- --format json --output results.json
+//     cargo run -p runner -- 3 9
+//
+// or generates random inputs to stress them with:
+//
+//     cargo run -p runner -- gen 9 --seed 3 --size 2000 | cargo run -p nine -- --puzzle_input -
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true)]
+struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Days to run, all of them if none are given.
+    days: Vec<u32>,
+
+    /// Directory containing the `dayN.txt` puzzle inputs.
+    #[arg(long, default_value = "inputs")]
+    inputs: PathBuf,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Also write every record of the batch to this file as a JSON array.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print a random puzzle input for a day. The same seed and knobs always
+    /// produce the same input.
+    Gen {
+        day: u32,
+
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// How many lines, items or rows to produce. Defaults to about the
+        /// size of a real input.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// How large each line, item or row gets, see `utils::generate`.
+        #[arg(long)]
+        scale: Option<u64>,
+    },
+}
+
+fn solve(day: u32, input: &str, report: &mut Report) -> Result<(), String> {
+    match day {
+        1 => {
+            let lines = utils::get_lines(input);
+            report.try_part(1, || one::part_one(&lines).ok_or("No answer".to_string()))?;
+            report.try_part(2, || one::part_two(&lines).ok_or("No answer".to_string()))?;
+        }
+        2 => {
+            let ranges = utils::get_int_ranges(input);
+            report.try_part(1, || two::part_one(&ranges).ok_or("No answer".to_string()))?;
+            report.try_part(2, || two::part_two(&ranges).ok_or("No answer".to_string()))?;
+        }
+        3 => {
+            let lines = utils::get_lines(input);
+            report.part(1, || three::part_one(&lines));
+            report.part(2, || three::part_two(&lines));
+        }
+        4 => {
+            let mut grid = BitGrid::from(&utils::get_binary_grid(input, '@')?);
+            report.part(1, || four::part_one(&grid));
+            report.part(2, || four::part_two(&mut grid));
+        }
+        5 => {
+            let inventory = five::parse_inventory(input)?;
+            report.part(1, || five::part_one(&inventory));
+            report.try_part(2, || five::part_two(&inventory))?;
+        }
+        6 => {
+            let lines = utils::get_lines(input);
+            let grid = utils::get_raw_grid_padded(input, ' ');
+            report.try_part(1, || six::part_one(&lines))?;
+            report.try_part(2, || six::part_two(&grid))?;
+        }
+        7 => {
+            let grid = utils::get_raw_grid(input)?;
+            report.part(1, || seven::part_one(&grid));
+            report.try_part(2, || seven::part_two(&grid))?;
+        }
+        8 => {
+            let lines = utils::get_lines(input);
+            report.try_part(1, || eight::part_one(&lines, 1000))?;
+            report.try_part(2, || eight::part_two(&lines))?;
+        }
+        9 => {
+            let points = nine::get_points(&utils::get_lines(input))?;
+            report.part(1, || nine::part_one(&points));
+            report.part(2, || nine::part_two(&points));
+        }
+        10 => {
+            let machines = ten::parse_machines(&utils::get_lines(input))?;
+            report.part(1, || ten::part_one(&machines));
+            report.try_part(2, || ten::part_two(&machines))?;
+        }
+        _ => return Err(format!("There is no day {day}")),
+    }
+
+    Ok(())
+}
+
+// Exits with a failure if any day can't be solved or the output can't be
+// written, so batch jobs notice.
+fn main() -> ExitCode {
+    let args = Args::parse();
+    if let Some(Command::Gen {
+        day,
+        seed,
+        size,
+        scale,
+    }) = args.command
+    {
+        match utils::generate::generate(day, seed, size, scale) {
+            Ok(input) => print!("{input}"),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    // Days named on the command line must have an input, the rest may not
+    let named = !args.days.is_empty();
+    let days = if args.days.is_empty() {
+        (1..=DAYS).collect()
+    } else {
+        args.days
+    };
+
+    let mut status = ExitCode::SUCCESS;
+    let mut records = vec![];
+    for day in days {
+        let path = args.inputs.join(format!("day{day}.txt"));
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Day {day}: {err}, skipping");
+                if named {
+                    status = ExitCode::FAILURE;
+                }
+                continue;
+            }
+        };
+
+        if args.format == Format::Text {
+            println!("Day {day}");
+        }
+        let mut report = Report::new(day, &path.display().to_string(), args.format);
+        if let Err(err) = solve(day, &input, &mut report) {
+            eprintln!("Day {day}: {err}");
+            status = ExitCode::FAILURE;
+        }
+        records.extend(report.into_records());
+    }
+
+    if let Some(output) = args.output
+        && let Err(err) = write_records(&output, &records)
+    {
+        eprintln!("{err}");
+        status = ExitCode::FAILURE;
+    }
+
+    status
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    utils::read_file(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))
+}
+
+fn write_records(path: &Path, records: &[utils::Record]) -> Result<(), String> {
+    fs::write(path, utils::report::records_to_json(records) + "\n")
+        .map_err(|err| format!("Failed to write {}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_generated_inputs() {
+        for day in 1..=DAYS {
+            // Day eight always makes 1000 connections, so it needs more boxes
+            // than that and only gets one try
+            let (size, scale, seeds) = match day {
+                2 => (Some(30), Some(1000), 3),
+                8 => (Some(1001), None, 1),
+                _ => (Some(30), None, 3),
+            };
+            for seed in 0..seeds {
+                let input = utils::generate::generate(day, seed, size, scale).unwrap();
+                let mut report = Report::new(day, "generated", Format::Json);
+
+                assert_eq!(Ok(()), solve(day, &input, &mut report), "day {day}");
+                assert_eq!(2, report.records().len());
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_input_error() {
+        let path = Path::new("no/such/dir/day1.txt");
+        let err = read_input(path).unwrap_err();
+
+        assert!(err.starts_with("Failed to read no/such/dir/day1.txt: "));
+    }
+
+    #[test]
+    fn test_write_records_error() {
+        let path = Path::new("no/such/dir/results.json");
+        let err = write_records(path, &[]).unwrap_err();
+
+        assert!(err.starts_with("Failed to write no/such/dir/results.json: "));
+    }
+}
@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod reference;
+
+use utils::Grid;
+
+// Timelines double at every splitter, so deep inputs have too many to count
+pub fn part_two(grid: &Grid<char>) -> Result<u64, String> {
+    let rows = grid.rows();
+    let cols = grid.cols();
+    let Some((start_row, start_col)) = grid.first_pos_of('S') else {
+        return Ok(0);
+    };
+
+    let mut timelines = vec![vec![0u64; cols]; rows];
+
+    timelines[rows - 1].fill(1);
+
+    for row in (0..rows - 1).rev() {
+        for col in 0..cols {
+            let down = (row + 1, col);
+            match grid.get(down.0, down.1) {
+                Some('^') => {
+                    let left = if col > 0 {
+                        timelines[row + 1][col - 1]
+                    } else {
+                        0
+                    };
+
+                    let right = if col + 1 < cols {
+                        timelines[row + 1][col + 1]
+                    } else {
+                        0
+                    };
+
+                    timelines[row][col] = left
+                        .checked_add(right)
+                        .ok_or_else(|| format!("Too many timelines at row {}", row + 1))?;
+                }
+
+                Some('.') => {
+                    timelines[row][col] = timelines[row + 1][col];
+                }
+
+                Some(_) | None => {}
+            }
+        }
+    }
+
+    Ok(timelines[start_row][start_col])
+}
+
+pub fn part_one(grid: &Grid<char>) -> u64 {
+    let start_pos = match grid.first_pos_of('S') {
+        Some(pos) => pos,
+        None => return 0,
+    };
+
+    let mut beams = vec![start_pos];
+    let mut seen = HashSet::new();
+    seen.insert(start_pos);
+    let mut res = 0;
+
+    while let Some((row, col)) = beams.pop() {
+        if row + 1 >= grid.rows() {
+            continue;
+        }
+        let down = (row + 1, col);
+
+        match grid.get(down.0, down.1) {
+            Some('^') => {
+                let left = (row + 1, col.wrapping_sub(1));
+                let right = (row + 1, col + 1);
+                let mut splitted = false;
+
+                if col > 0 && seen.insert(left) {
+                    beams.push(left);
+                    splitted = true;
+                }
+
+                if col + 1 < grid.cols() && seen.insert(right) {
+                    beams.push(right);
+                    splitted = true;
+                }
+
+                if splitted {
+                    res += 1;
+                }
+            }
+
+            Some('.') => {
+                if seen.insert(down) {
+                    beams.push(down);
+                }
+            }
+
+            Some(_) | None => {}
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 7,
+        parse: |input| utils::get_raw_grid(input).unwrap(),
+        part_one: |grid| part_one(grid),
+        part_two: |grid| part_two(grid).unwrap(),
+    }
+
+    #[test]
+    fn test_without_start() {
+        for input in ["", "...\n.^.\n"] {
+            let grid = utils::get_raw_grid(input).unwrap();
+
+            assert_eq!(0, part_one(&grid));
+            assert_eq!(Ok(0), part_two(&grid));
+        }
+    }
+
+    #[test]
+    fn test_too_many_timelines() {
+        // Every row of splitters about doubles the timelines
+        let mut input = format!("{}S{}\n", ".".repeat(100), ".".repeat(100));
+        for _ in 0..70 {
+            input += &format!("{}\n{}\n", "^".repeat(201), ".".repeat(201));
+        }
+        let grid = utils::get_raw_grid(&input).unwrap();
+
+        assert!(part_two(&grid).unwrap_err().starts_with("Too many timelines at row "));
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(7, 100, (16, 15), true, |input| {
+            let grid = utils::get_raw_grid(input).ok()?;
+            grid.first_pos_of('S')?;
+            let fast = (part_one(&grid), part_two(&grid).unwrap());
+            let slow = (reference::part_one(&grid), reference::part_two(&grid));
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use utils::Grid;
+
+// Row of the first splitter below `(row, col)`
+fn next_splitter(grid: &Grid<char>, row: usize, col: usize) -> Option<usize> {
+    (row + 1..grid.rows()).find(|&r| grid.get(r, col) == Some('^'))
+}
+
+// Beams pushed off the side of the manifold are lost
+fn sides(grid: &Grid<char>, col: usize) -> Vec<usize> {
+    [col.checked_sub(1), Some(col + 1)]
+        .into_iter()
+        .flatten()
+        .filter(|&c| c < grid.cols())
+        .collect()
+}
+
+fn hit_splitters(grid: &Grid<char>, (row, col): (usize, usize), hit: &mut HashSet<(usize, usize)>) {
+    let Some(splitter) = next_splitter(grid, row, col) else {
+        return;
+    };
+    if hit.insert((splitter, col)) {
+        for side in sides(grid, col) {
+            hit_splitters(grid, (splitter, side), hit);
+        }
+    }
+}
+
+// Follows every timeline on its own, so there must not be many.
+fn timelines(grid: &Grid<char>, (row, col): (usize, usize)) -> u64 {
+    match next_splitter(grid, row, col) {
+        Some(splitter) => sides(grid, col)
+            .into_iter()
+            .map(|side| timelines(grid, (splitter, side)))
+            .sum(),
+        None => 1,
+    }
+}
+
+pub fn part_one(grid: &Grid<char>) -> u64 {
+    let Some(start) = grid.first_pos_of('S') else {
+        return 0;
+    };
+    let mut hit = HashSet::new();
+    hit_splitters(grid, start, &mut hit);
+
+    hit.len() as u64
+}
+
+pub fn part_two(grid: &Grid<char>) -> u64 {
+    grid.first_pos_of('S')
+        .map_or(0, |start| timelines(grid, start))
+}
@@ -0,0 +1,145 @@
+use utils::Grid;
+
+#[derive(Debug)]
+enum Operation {
+    Add,
+    Mult,
+}
+
+impl TryFrom<char> for Operation {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '+' => Ok(Operation::Add),
+            '*' => Ok(Operation::Mult),
+            other => Err(format!("Invalid operation char: {}", other)),
+        }
+    }
+}
+
+impl Operation {
+    fn execute(&self, a: u64, b: u64) -> Result<u64, String> {
+        let (result, symbol) = match &self {
+            Operation::Add => (a.checked_add(b), '+'),
+            Operation::Mult => (a.checked_mul(b), '*'),
+        };
+
+        result.ok_or_else(|| format!("{a} {symbol} {b} overflows"))
+    }
+}
+
+fn str_to_op(s: &str) -> Option<(Operation, u64)> {
+    if s.len() != 1 {
+        return None;
+    }
+
+    s.chars()
+        .next()
+        .and_then(|c| c.try_into().ok())
+        .map(|op| match op {
+            Operation::Add => (op, 0),
+            Operation::Mult => (op, 1),
+        })
+}
+
+fn char_to_op(c: char) -> Option<(Operation, u64)> {
+    c.try_into().ok().map(|op| match op {
+        Operation::Add => (op, 0),
+        Operation::Mult => (op, 1),
+    })
+}
+
+pub fn part_two(grid: &Grid<char>) -> Result<u64, String> {
+    // Each problem is read column by column, so work on the transposed grid
+    // where every row is one column: digits first, then the operator cell.
+    let columns = grid.transpose();
+    let mut current: Option<(Operation, u64)> = None;
+    let mut sum = 0u64;
+
+    for j in 0..columns.rows() {
+        let Some((&op_char, digits)) = columns.get_row(j).split_last() else {
+            continue;
+        };
+
+        // Operators sit in the leftmost column of their problem
+        if let Some(op) = char_to_op(op_char)
+            && let Some((_, prev)) = current.replace(op)
+        {
+            sum = Operation::Add.execute(sum, prev)?;
+        }
+
+        if !digits.iter().any(|c| c.is_ascii_digit()) {
+            continue;
+        }
+
+        let n = digits
+            .iter()
+            .filter_map(|c| c.to_digit(10))
+            .try_fold(0, |n: u64, d| n.checked_mul(10)?.checked_add(d as u64))
+            .ok_or_else(|| format!("Column {} holds too large a number", j + 1))?;
+
+        if let Some((op, prev)) = &mut current {
+            *prev = op.execute(n, *prev)?;
+        }
+    }
+
+    Operation::Add.execute(sum, current.map(|(_, prev)| prev).unwrap_or(0))
+}
+
+pub fn part_one(lines: &[String]) -> Result<u64, String> {
+    let rows = lines.len();
+    if rows == 0 {
+        return Ok(0);
+    }
+
+    let mut ops: Vec<(Operation, u64)> = utils::split_whitespace(&lines[rows - 1])
+        .iter()
+        .filter_map(|s| str_to_op(s))
+        .collect();
+
+    for line in lines[0..rows - 1].iter() {
+        let nums = utils::get_uints(line);
+        if nums.len() != ops.len() {
+            return Ok(0);
+        }
+
+        for (idx, n) in nums.iter().enumerate() {
+            let (op, prev) = &mut ops[idx];
+            *prev = op.execute(*n, *prev)?;
+        }
+    }
+
+    ops.iter()
+        .try_fold(0, |sum, (_, res)| Operation::Add.execute(sum, *res))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 6,
+        part_one: |input| part_one(&utils::get_lines(input)).unwrap(),
+        part_two: |input| part_two(&utils::get_raw_grid_padded(input, ' ')).unwrap(),
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Ok(0), part_one(&[]));
+        assert_eq!(Ok(0), part_two(&utils::get_raw_grid_padded("", ' ')));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "18446744073709551615 2\n1 3\n+ *\n";
+        let err = Err("1 + 18446744073709551615 overflows".to_string());
+
+        assert_eq!(err, part_one(&utils::get_lines(input)));
+
+        // Part two reads its numbers top to bottom, here a 20 digit one
+        let input = "9\n".repeat(20) + "*";
+        let err = Err("Column 1 holds too large a number".to_string());
+        assert_eq!(err, part_two(&utils::get_raw_grid_padded(&input, ' ')));
+    }
+}
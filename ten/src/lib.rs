@@ -0,0 +1,297 @@
+use std::collections::{HashMap, VecDeque};
+use std::str::FromStr;
+
+use utils::AocParse;
+
+#[cfg(test)]
+mod reference;
+
+// Indicator lights as a bit mask, light `i` in bit `i`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Lights {
+    target: u16,
+    len: usize,
+}
+
+impl FromStr for Lights {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() > 16 {
+            return Err(format!("expected at most 16 lights, found {}", s.len()));
+        }
+
+        let mut target = 0;
+        for (i, c) in s.chars().enumerate() {
+            match c {
+                '#' => target |= 1 << i,
+                '.' => {}
+                other => return Err(format!("invalid light `{other}`")),
+            }
+        }
+
+        Ok(Self {
+            target,
+            len: s.len(),
+        })
+    }
+}
+
+#[derive(Debug, AocParse)]
+#[aoc(format = "[{lights}] {buttons:(,)*} {{{joltage:,}}}")]
+pub struct Machine {
+    lights: Lights,
+    buttons: Vec<Vec<usize>>,
+    joltage: Vec<u64>,
+}
+
+// Part two tries every subset of buttons
+const MAX_BUTTONS: usize = 16;
+
+impl Machine {
+    // Buttons may only wire up counters that have both a light and a joltage
+    fn check_buttons(self) -> Result<Self, String> {
+        if self.buttons.len() > MAX_BUTTONS {
+            return Err(format!(
+                "Field `buttons`: expected at most {MAX_BUTTONS} buttons, found {}",
+                self.buttons.len()
+            ));
+        }
+
+        let counters = self.lights.len.min(self.joltage.len());
+        match self.buttons.iter().flatten().find(|&&i| i >= counters) {
+            Some(i) => Err(format!(
+                "Field `buttons`: index {i} out of range for {counters} counters"
+            )),
+            None => Ok(self),
+        }
+    }
+}
+
+pub fn parse_machines(lines: &[String]) -> Result<Vec<Machine>, String> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(idx, line)| {
+            line.parse()
+                .and_then(Machine::check_buttons)
+                .map_err(|err| format!("Line {}: {err}", idx + 1))
+        })
+        .collect()
+}
+
+fn get_button_masks(buttons: &[Vec<usize>]) -> Vec<u16> {
+    buttons
+        .iter()
+        .map(|bits| bits.iter().fold(0, |mask, &i| mask | 1 << i))
+        .collect()
+}
+
+fn get_min_buttons(buttons: &[u16], target: u16, num_bits: u8) -> Option<Vec<u16>> {
+    if num_bits > 16 {
+        panic!("Maximum number of indicators is 16");
+    }
+
+    let mut visited = vec![false; 1 << num_bits];
+    let mut parent = vec![None::<(u16, u16)>; 1 << num_bits];
+
+    let mut queue = VecDeque::new();
+    queue.push_back(0u16);
+    visited[0] = true;
+
+    while let Some(state) = queue.pop_front() {
+        if state == target {
+            let mut path = vec![];
+            let mut current = state;
+            while let Some((prev, button)) = parent[current as usize] {
+                path.push(button);
+                current = prev;
+            }
+
+            return Some(path);
+        }
+
+        for &button in buttons {
+            let next = state ^ button;
+            let idx = next as usize;
+            if !visited[idx] {
+                visited[idx] = true;
+                parent[idx] = Some((state, button));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    None
+}
+
+// Every way of pressing each button at most once, grouped by which counters
+// end up odd: (increase per counter, number of presses).
+type Combinations = HashMap<Vec<u64>, Vec<(Vec<u64>, u64)>>;
+
+fn get_combinations(buttons: &[Vec<usize>], counters: usize) -> Combinations {
+    let mut combinations: Combinations = HashMap::new();
+
+    for subset in 0..1u32 << buttons.len() {
+        let mut increase = vec![0; counters];
+        for (_, button) in buttons
+            .iter()
+            .enumerate()
+            .filter(|(b, _)| subset >> b & 1 == 1)
+        {
+            for &i in button {
+                increase[i] += 1;
+            }
+        }
+
+        let parity = increase.iter().map(|n| n % 2).collect();
+        combinations
+            .entry(parity)
+            .or_default()
+            .push((increase, subset.count_ones() as u64));
+    }
+
+    combinations
+}
+
+// Any solution presses each button an even number of times plus a subset
+// fixing the odd counters, so remove that subset and solve for half the rest.
+// Joltages near `u64::MAX` can take more presses than it holds.
+fn get_min_presses(
+    target: &[u64],
+    combinations: &Combinations,
+    cache: &mut HashMap<Vec<u64>, Option<u128>>,
+) -> Option<u128> {
+    if target.iter().all(|&n| n == 0) {
+        return Some(0);
+    }
+    if let Some(&presses) = cache.get(target) {
+        return presses;
+    }
+
+    let parity: Vec<u64> = target.iter().map(|n| n % 2).collect();
+    let mut best = None;
+
+    for (increase, presses) in combinations.get(&parity).into_iter().flatten() {
+        if increase.iter().zip(target).any(|(inc, t)| inc > t) {
+            continue;
+        }
+
+        let half: Vec<u64> = target
+            .iter()
+            .zip(increase)
+            .map(|(t, inc)| (t - inc) / 2)
+            .collect();
+        if let Some(rest) = get_min_presses(&half, combinations, cache) {
+            let total = *presses as u128 + 2 * rest;
+            best = Some(best.map_or(total, |b: u128| b.min(total)));
+        }
+    }
+
+    cache.insert(target.to_vec(), best);
+    best
+}
+
+pub fn part_two(machines: &[Machine]) -> Result<u64, String> {
+    let presses = utils::par_map_sum(machines, |machine| {
+        let combinations = get_combinations(&machine.buttons, machine.joltage.len());
+        let min_presses = get_min_presses(&machine.joltage, &combinations, &mut HashMap::new());
+        min_presses.unwrap_or(0)
+    });
+
+    presses
+        .try_into()
+        .map_err(|_| format!("{presses} presses don't fit in a u64"))
+}
+
+pub fn part_one(machines: &[Machine]) -> usize {
+    utils::par_map_sum(machines, |machine| {
+        let Lights { target, len } = machine.lights;
+        let buttons = get_button_masks(&machine.buttons);
+
+        let min_buttons = get_min_buttons(&buttons, target, len as u8);
+        min_buttons.map(|btns| btns.len()).unwrap_or(0)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 10,
+        parse: |input| parse_machines(&utils::get_lines(input)).unwrap(),
+        part_one: |machines| part_one(machines),
+        part_two: |machines| part_two(machines).unwrap(),
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let lines = utils::get_lines("[.#] (0) {1}\n[.#] (0,x) {1}");
+
+        assert_eq!(
+            Err(
+                "Line 2: Field `buttons`: invalid value `x`: invalid digit found in string"
+                    .to_string()
+            ),
+            parse_machines(&lines).map(|machines| machines.len())
+        );
+    }
+
+    #[test]
+    fn test_button_out_of_range() {
+        let lines = utils::get_lines("[.#] (0) (0,2) {1,2,3}");
+
+        assert_eq!(
+            Err("Line 1: Field `buttons`: index 2 out of range for 2 counters".to_string()),
+            parse_machines(&lines).map(|machines| machines.len())
+        );
+    }
+
+    #[test]
+    fn test_too_many_buttons() {
+        let lines = utils::get_lines(&format!("[.#] {}{{1,2}}", "(0,1) ".repeat(17)));
+
+        assert_eq!(
+            Err("Line 1: Field `buttons`: expected at most 16 buttons, found 17".to_string()),
+            parse_machines(&lines).map(|machines| machines.len())
+        );
+    }
+
+    // The first machine needs (0,1) once and (0) twice, the second can't
+    // reach its last counter and adds nothing
+    #[test]
+    fn test_min_presses() {
+        let lines = utils::get_lines("[#.] (0) (0,1) {3,1}\n[..] (0) {0,2}");
+        let machines = parse_machines(&lines).unwrap();
+
+        assert_eq!(Ok(3), part_two(&machines));
+    }
+
+    #[test]
+    fn test_huge_joltage() {
+        let lines = utils::get_lines(&format!("[#] (0) {{{}}}", u64::MAX));
+        let machines = parse_machines(&lines).unwrap();
+        assert_eq!(Ok(u64::MAX), part_two(&machines));
+
+        let lines = [lines[0].clone(), lines[0].clone()];
+        let machines = parse_machines(&lines).unwrap();
+        let err = Err("36893488147419103230 presses don't fit in a u64".to_string());
+        assert_eq!(err, part_two(&machines));
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(10, 50, (5, 3), true, |input| {
+            let machines = parse_machines(&utils::get_lines(input)).ok()?;
+            let fast = (part_one(&machines), part_two(&machines).unwrap());
+            let slow = (
+                reference::part_one(&machines),
+                reference::part_two(&machines),
+            );
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::Machine;
+
+// Tries every set of buttons, each pressed once.
+fn min_buttons(machine: &Machine) -> Option<u64> {
+    let target: Vec<bool> = (0..machine.lights.len)
+        .map(|i| machine.lights.target >> i & 1 == 1)
+        .collect();
+
+    let mut min = None;
+    for subset in 0u32..1 << machine.buttons.len() {
+        let mut lights = vec![false; target.len()];
+        for (b, button) in machine.buttons.iter().enumerate() {
+            if subset >> b & 1 == 1 {
+                for &i in button {
+                    lights[i] = !lights[i];
+                }
+            }
+        }
+        if lights == target {
+            let presses = u64::from(subset.count_ones());
+            min = Some(min.map_or(presses, |min: u64| min.min(presses)));
+        }
+    }
+
+    min
+}
+
+// Breadth first search over the counter values, one press at a time, so
+// joltages must stay small.
+fn min_presses(machine: &Machine) -> Option<u64> {
+    let start = vec![0; machine.joltage.len()];
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((counters, presses)) = queue.pop_front() {
+        if counters == machine.joltage {
+            return Some(presses);
+        }
+
+        for button in &machine.buttons {
+            let mut next = counters.clone();
+            for &i in button {
+                next[i] += 1;
+            }
+            let overshoots = next.iter().zip(&machine.joltage).any(|(n, j)| n > j);
+            if !overshoots && seen.insert(next.clone()) {
+                queue.push_back((next, presses + 1));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn part_one(machines: &[Machine]) -> usize {
+    machines
+        .iter()
+        .map(|machine| min_buttons(machine).unwrap_or(0) as usize)
+        .sum()
+}
+
+pub fn part_two(machines: &[Machine]) -> u64 {
+    machines
+        .iter()
+        .map(|machine| min_presses(machine).unwrap_or(0))
+        .sum()
+}
@@ -0,0 +1,124 @@
+#[cfg(test)]
+mod reference;
+
+fn find_max_tuple(numbers: &[u64]) -> u64 {
+    let mut first = 0;
+    let mut first_idx = 0;
+    let mut second = 0;
+    let size = numbers.len();
+
+    for (idx, &n) in numbers[..size - 1].iter().enumerate() {
+        if n > first {
+            first = n;
+            first_idx = idx;
+        }
+    }
+
+    for &n in &numbers[first_idx + 1..] {
+        if n > second {
+            second = n;
+        }
+    }
+
+    first * 10 + second
+}
+
+fn find_max_combination<const T: usize>(numbers: &[u64]) -> [u64; T] {
+    let mut combination: [u64; T] = [0; T];
+    let mut first_index = 0;
+
+    for i in (0..T).rev() {
+        let last_index = numbers.len() - i;
+        let mut max = 0;
+        let mut max_idx = 0;
+        for (idx, &n) in numbers[first_index..last_index].iter().enumerate() {
+            if n > max {
+                max = n;
+                max_idx = idx;
+            }
+        }
+        first_index += max_idx + 1;
+        combination[T - (i + 1)] = max;
+    }
+
+    combination
+}
+
+fn get_numbers(line: &str) -> Vec<u64> {
+    line.chars()
+        .filter_map(|c| c.to_digit(10).map(|d| d as u64))
+        .collect()
+}
+
+fn get_decimal_repr<const T: usize>(digits: &[u64; T]) -> u64 {
+    let mut decimal = 0;
+    for (idx, d) in digits.iter().enumerate() {
+        let exp = (T - (idx + 1)) as u32;
+        decimal += d * 10u64.pow(exp);
+    }
+
+    decimal
+}
+
+// Banks with fewer batteries than need turning on add nothing
+pub fn part_one(lines: &[String]) -> u64 {
+    utils::par_map_sum(lines, |line| {
+        let numbers = get_numbers(line);
+        if numbers.len() < 2 {
+            return 0;
+        }
+        find_max_tuple(&numbers)
+    })
+}
+
+pub fn part_two(lines: &[String]) -> u64 {
+    utils::par_map_sum(lines, |line| {
+        let numbers = get_numbers(line);
+        if numbers.len() < 12 {
+            return 0;
+        }
+        let combination = find_max_combination::<12>(&numbers);
+        get_decimal_repr(&combination)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 3,
+        parse: |input| utils::get_lines(input),
+        part_one: |lines| part_one(lines),
+        part_two: |lines| part_two(lines),
+    }
+
+    #[test]
+    fn test_against_reference() {
+        utils::differential::check(3, 50, (5, 16), true, |input| {
+            let lines = utils::get_lines(input);
+            let fast = (part_one(&lines), part_two(&lines));
+            let slow = (reference::part_one(&lines), reference::part_two(&lines));
+            Some((fast, slow))
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_short_and_empty_banks() {
+        let lines = utils::get_lines("7\n\n1234567891234");
+
+        assert_eq!(94, part_one(&lines));
+        assert_eq!(234567891234, part_two(&lines));
+    }
+
+    // A window of only zeros used to leave its index absolute, skipping
+    // digits or running past the end of the bank
+    #[test]
+    fn test_zero_windows() {
+        let lines = utils::get_lines("000000000000\n00000000000009\n1000000000001");
+
+        assert_eq!(9 + 11, part_one(&lines));
+        assert_eq!(9 + 100000000001, part_two(&lines));
+    }
+}
@@ -0,0 +1,45 @@
+fn get_digits(line: &str) -> Vec<u64> {
+    line.chars()
+        .filter_map(|c| c.to_digit(10).map(u64::from))
+        .collect()
+}
+
+// Tries every pair of batteries.
+pub fn part_one(lines: &[String]) -> u64 {
+    let mut sum = 0;
+    for line in lines {
+        let digits = get_digits(line);
+        let mut max = 0;
+        for i in 0..digits.len() {
+            for j in i + 1..digits.len() {
+                max = max.max(digits[i] * 10 + digits[j]);
+            }
+        }
+        sum += max;
+    }
+
+    sum
+}
+
+// Tries every choice of twelve batteries, so banks must stay short.
+pub fn part_two(lines: &[String]) -> u64 {
+    let mut sum = 0;
+    for line in lines {
+        let digits = get_digits(line);
+        assert!(digits.len() <= 20, "Bank too long for the reference");
+
+        let mut max = 0;
+        for mask in 0u32..1 << digits.len() {
+            if mask.count_ones() != 12 {
+                continue;
+            }
+            let joltage = (0..digits.len())
+                .filter(|i| mask & 1 << i != 0)
+                .fold(0, |joltage, i| joltage * 10 + digits[i]);
+            max = max.max(joltage);
+        }
+        sum += max;
+    }
+
+    sum
+}
@@ -0,0 +1,103 @@
+fn get_squares(low: u64, high: u64) -> Vec<u64> {
+    let mut result = vec![];
+    for d in low..=high {
+        let d_str = d.to_string();
+        if d_str.len() % 2 != 0 {
+            continue;
+        }
+        let mid = d_str.len() / 2;
+        if d_str[0..mid] == d_str[mid..] {
+            result.push(d);
+        }
+    }
+    result
+}
+
+fn get_divisors(n: usize) -> Vec<usize> {
+    let mut divisors = vec![];
+    for i in 1..=n / 2 {
+        if n.is_multiple_of(i) {
+            divisors.push(i);
+        }
+    }
+
+    divisors
+}
+
+fn get_repeated_string(s: &str, k: usize) -> String {
+    let mut result = String::with_capacity(s.len() * k);
+    for _ in 0..k {
+        result.push_str(s);
+    }
+
+    result
+}
+
+fn get_periodic(low: u64, high: u64, divisor_cache: &mut [Option<Vec<usize>>]) -> Vec<u64> {
+    let mut result = vec![];
+    for n in low..=high {
+        let s = n.to_string();
+        let s_len = s.len();
+        let divisors = divisor_cache[s_len].get_or_insert_with(|| get_divisors(s_len));
+
+        'check_periodic: for &mut div in divisors {
+            let block = &s[..div];
+            let factor = s_len / div;
+
+            if get_repeated_string(block, factor) == s {
+                result.push(n);
+                break 'check_periodic;
+            }
+        }
+    }
+    result
+}
+
+// IDs near `u64::MAX` add up past it, so both parts sum in `u128` and have no
+// answer if the total doesn't fit
+pub fn part_one(ranges: &[(u64, u64)]) -> Option<u64> {
+    let count = utils::par_map_sum(ranges, |&(low, high)| {
+        let squares = get_squares(low, high);
+        squares.iter().map(|&n| n as u128).sum::<u128>()
+    });
+    count.try_into().ok()
+}
+
+pub fn part_two(ranges: &[(u64, u64)]) -> Option<u64> {
+    const NONE_VEC: Option<Vec<usize>> = None;
+    let mut divisors_cache: [Option<Vec<usize>>; 21] = [NONE_VEC; 21];
+    // Pre-compute cache: u64 has a maximum of 20 digits
+    for (i, divisors) in divisors_cache.iter_mut().enumerate().skip(1) {
+        *divisors = Some(get_divisors(i));
+    }
+
+    // Every range gets its own copy of the cache so they can run in parallel
+    let count = utils::par_map_sum(ranges, |&(low, high)| {
+        let squares = get_periodic(low, high, &mut divisors_cache.clone());
+        squares.iter().map(|&n| n as u128).sum::<u128>()
+    });
+    count.try_into().ok()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    utils::example_tests! {
+        day: 2,
+        parse: |input| utils::get_int_ranges::<u64>(input),
+        part_one: |ranges| part_one(ranges).unwrap(),
+        part_two: |ranges| part_two(ranges).unwrap(),
+    }
+
+    #[test]
+    fn test_largest_ids() {
+        let ranges = utils::get_int_ranges("18446744073709551610-18446744073709551615");
+        assert_eq!(Some(0), part_one(&ranges));
+        assert_eq!(Some(0), part_two(&ranges));
+
+        let ranges = [(18446744071844674407, 18446744071844674407); 2];
+        assert_eq!(None, part_one(&ranges));
+        assert_eq!(None, part_two(&ranges));
+    }
+}
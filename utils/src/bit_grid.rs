@@ -0,0 +1,330 @@
+use crate::grid::Grid;
+
+const WORD_BITS: usize = 64;
+
+// Boolean grid with 64 cells per word. Bit `j % 64` of word `j / 64` in a row
+// holds column `j`; bits past the last column are always zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    rows: usize,
+    cols: usize,
+    words_per_row: usize,
+    data: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(rows: usize, cols: usize) -> Self {
+        let words_per_row = cols.div_ceil(WORD_BITS);
+
+        Self {
+            rows,
+            cols,
+            words_per_row,
+            data: vec![0; rows * words_per_row],
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<bool> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let word = self.data[row * self.words_per_row + col / WORD_BITS];
+        Some(word >> (col % WORD_BITS) & 1 == 1)
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, value: bool) -> Option<bool> {
+        let previous = self.get(row, col)?;
+        let word = &mut self.data[row * self.words_per_row + col / WORD_BITS];
+        let bit = 1u64 << (col % WORD_BITS);
+
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+
+        Some(previous)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.data
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.iter().all(|&word| word == 0)
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.rows).flat_map(move |i| {
+            (0..self.cols)
+                .filter(move |&j| self.get(i, j) == Some(true))
+                .map(move |j| (i, j))
+        })
+    }
+
+    pub fn and(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    pub fn or(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+    pub fn and_not(&self, other: &Self) -> Self {
+        self.zip_words(other, |a, b| a & !b)
+    }
+
+    // Number of set cells among the eight neighbours of every cell, computed
+    // a word at a time with bit-sliced adders.
+    pub fn neighbour_counts(&self) -> NeighbourCounts {
+        let mut planes: [BitGrid; 4] = std::array::from_fn(|_| Self::new(self.rows, self.cols));
+
+        for i in 0..self.rows {
+            for w in 0..self.words_per_row {
+                let mut inputs = Vec::with_capacity(8);
+                for dr in [-1isize, 0, 1] {
+                    let Some(r) = i.checked_add_signed(dr).filter(|&r| r < self.rows) else {
+                        continue;
+                    };
+
+                    inputs.push(self.shifted_west(r, w));
+                    inputs.push(self.shifted_east(r, w));
+                    if dr != 0 {
+                        inputs.push(self.word(r, w));
+                    }
+                }
+
+                let mut sum = [0u64; 4];
+                for input in inputs {
+                    let mut carry = input;
+                    for plane in &mut sum {
+                        let next = *plane & carry;
+                        *plane ^= carry;
+                        carry = next;
+                    }
+                }
+
+                let mask = self.valid_mask(w);
+                for (plane, bits) in planes.iter_mut().zip(sum) {
+                    plane.data[i * self.words_per_row + w] = bits & mask;
+                }
+            }
+        }
+
+        NeighbourCounts { planes }
+    }
+
+    fn word(&self, row: usize, w: usize) -> u64 {
+        self.data[row * self.words_per_row + w]
+    }
+
+    // Bit `j` holds the cell to the west, i.e. column `j - 1`.
+    fn shifted_west(&self, row: usize, w: usize) -> u64 {
+        let carry = if w > 0 {
+            self.word(row, w - 1) >> (WORD_BITS - 1)
+        } else {
+            0
+        };
+        (self.word(row, w) << 1) | carry
+    }
+
+    // Bit `j` holds the cell to the east, i.e. column `j + 1`.
+    fn shifted_east(&self, row: usize, w: usize) -> u64 {
+        let carry = if w + 1 < self.words_per_row {
+            self.word(row, w + 1) << (WORD_BITS - 1)
+        } else {
+            0
+        };
+        (self.word(row, w) >> 1) | carry
+    }
+
+    fn valid_mask(&self, w: usize) -> u64 {
+        let used = self.cols - w * WORD_BITS;
+        if used >= WORD_BITS {
+            u64::MAX
+        } else {
+            (1u64 << used) - 1
+        }
+    }
+
+    fn zip_words(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        assert!(
+            self.rows == other.rows && self.cols == other.cols,
+            "BitGrid dimensions differ: {}x{} and {}x{}",
+            self.rows,
+            self.cols,
+            other.rows,
+            other.cols
+        );
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(&a, &b)| op(a, b))
+            .collect();
+        Self { data, ..*self }
+    }
+}
+
+impl From<&Grid<u8>> for BitGrid {
+    fn from(grid: &Grid<u8>) -> Self {
+        let mut bits = Self::new(grid.rows(), grid.cols());
+        for ((i, j), &elem) in grid.enumerate_row_major() {
+            bits.set(i, j, elem != 0);
+        }
+
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<u8> {
+    fn from(bits: &BitGrid) -> Self {
+        let data = (0..bits.rows)
+            .flat_map(|i| (0..bits.cols).map(move |j| u8::from(bits.get(i, j) == Some(true))))
+            .collect();
+
+        Grid::new(data, bits.rows)
+    }
+}
+
+// Per-cell counts stored as four bit planes, so the count of a cell is
+// `sum(plane[k] << k)`.
+#[derive(Debug, Clone)]
+pub struct NeighbourCounts {
+    planes: [BitGrid; 4],
+}
+
+impl NeighbourCounts {
+    pub fn get(&self, row: usize, col: usize) -> Option<u8> {
+        let mut count = 0;
+        for (k, plane) in self.planes.iter().enumerate() {
+            count |= u8::from(plane.get(row, col)?) << k;
+        }
+
+        Some(count)
+    }
+
+    // Cells whose count is strictly below `k`.
+    pub fn less_than(&self, k: u8) -> BitGrid {
+        let template = &self.planes[0];
+        if k > 8 {
+            let mut all = BitGrid::new(template.rows, template.cols);
+            for w in 0..template.words_per_row {
+                let mask = template.valid_mask(w);
+                for i in 0..template.rows {
+                    all.data[i * template.words_per_row + w] = mask;
+                }
+            }
+            return all;
+        }
+
+        let mut result = BitGrid::new(template.rows, template.cols);
+        for idx in 0..template.data.len() {
+            let mut less = 0u64;
+            let mut equal = u64::MAX;
+            for bit in (0..4).rev() {
+                let plane = self.planes[bit].data[idx];
+                if (k >> bit) & 1 == 1 {
+                    less |= equal & !plane;
+                    equal &= plane;
+                } else {
+                    equal &= !plane;
+                }
+            }
+
+            result.data[idx] = less & template.valid_mask(idx % template.words_per_row);
+        }
+
+        result
+    }
+
+    pub fn to_grid(&self) -> Grid<u8> {
+        let template = &self.planes[0];
+        let data = (0..template.rows)
+            .flat_map(|i| (0..template.cols).map(move |j| self.get(i, j).unwrap_or(0)))
+            .collect();
+
+        Grid::new(data, template.rows)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn naive_counts(grid: &Grid<u8>) -> Grid<u8> {
+        let mut counts = Grid::fill(grid.rows(), grid.cols(), 0u8);
+        for ((i, j), _) in grid.enumerate_row_major() {
+            for dr in -1isize..=1 {
+                for dc in -1isize..=1 {
+                    if (dr, dc) == (0, 0) {
+                        continue;
+                    }
+                    let neighbour = i
+                        .checked_add_signed(dr)
+                        .zip(j.checked_add_signed(dc))
+                        .and_then(|(r, c)| grid.get(r, c));
+                    counts[(i, j)] += neighbour.unwrap_or(0);
+                }
+            }
+        }
+
+        counts
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let grid = Grid::new(vec![1, 0, 1, 1, 1, 0], 2);
+        let bits = BitGrid::from(&grid);
+
+        assert_eq!(4, bits.count_ones());
+        assert_eq!(Some(false), bits.get(0, 1));
+        assert_eq!(None, bits.get(2, 0));
+        assert_eq!(grid, Grid::from(&bits));
+    }
+
+    #[test]
+    fn test_neighbour_counts_across_words() {
+        // Wide enough to span several words, with a pattern that crosses
+        // the word boundaries.
+        let cols = 150;
+        let data = (0..3 * cols)
+            .map(|idx| u8::from(idx % 3 != 0 || idx % 7 == 0))
+            .collect();
+        let grid = Grid::new(data, 3);
+        let bits = BitGrid::from(&grid);
+        let counts = bits.neighbour_counts();
+
+        assert_eq!(naive_counts(&grid), counts.to_grid());
+
+        let fewer: Grid<u8> = Grid::from(&counts.less_than(4));
+        for ((i, j), &count) in naive_counts(&grid).enumerate_row_major() {
+            assert_eq!(u8::from(count < 4), fewer[(i, j)]);
+        }
+        assert_eq!(3 * cols, counts.less_than(9).count_ones());
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = BitGrid::from(&Grid::new(vec![1, 1, 0, 0], 2));
+        let b = BitGrid::from(&Grid::new(vec![0, 1, 1, 0], 2));
+
+        assert_eq!(vec![(0, 1)], a.and(&b).positions().collect::<Vec<_>>());
+        assert_eq!(3, a.or(&b).count_ones());
+        assert_eq!(vec![(0, 0)], a.and_not(&b).positions().collect::<Vec<_>>());
+        assert!(a.and_not(&a).is_empty());
+    }
+}
@@ -0,0 +1,69 @@
+#[derive(Debug, Clone)]
+pub struct Compression<T>
+where
+    T: Copy + Ord,
+{
+    values: Vec<T>,
+}
+
+impl<T: Copy + Ord> Compression<T> {
+    pub fn new(values: impl IntoIterator<Item = T>) -> Self {
+        let mut values: Vec<T> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+
+        Self { values }
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn index_of(&self, value: T) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    pub fn value(&self, idx: usize) -> Option<T> {
+        self.values.get(idx).copied()
+    }
+
+    // Gapped indices interleave the compressed values with the open intervals
+    // between them: value `i` maps to `2 * i` and the gap after it to `2 * i + 1`.
+    pub fn gapped_len(&self) -> usize {
+        (2 * self.values.len()).saturating_sub(1)
+    }
+
+    pub fn gapped_index_of(&self, value: T) -> Option<usize> {
+        self.index_of(value).map(|idx| 2 * idx)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_index_of() {
+        let compression = Compression::new([11, 2, 7, 2, 9]);
+
+        assert_eq!(4, compression.len());
+        assert_eq!(Some(0), compression.index_of(2));
+        assert_eq!(Some(3), compression.index_of(11));
+        assert_eq!(None, compression.index_of(8));
+        assert_eq!(Some(9), compression.value(2));
+    }
+
+    #[test]
+    fn test_gapped_index_of() {
+        let compression = Compression::new([11, 2, 7, 9]);
+
+        assert_eq!(7, compression.gapped_len());
+        assert_eq!(Some(0), compression.gapped_index_of(2));
+        assert_eq!(Some(4), compression.gapped_index_of(9));
+        assert_eq!(Some(6), compression.gapped_index_of(11));
+    }
+}
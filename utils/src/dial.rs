@@ -0,0 +1,241 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            other => Err(format!("Invalid direction char: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub direction: Direction,
+    pub clicks: u64,
+}
+
+// Outcome of a single rotation. `passes` counts every click that lands on
+// the target, including the final one when the rotation stops there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialStep {
+    pub rotation: Rotation,
+    pub from: u64,
+    pub to: u64,
+    pub stopped_on_target: bool,
+    pub passes: u64,
+}
+
+// A circular dial with positions `0..size`.
+#[derive(Debug, Clone)]
+pub struct Dial {
+    size: u64,
+    position: u64,
+    target: u64,
+}
+
+impl Dial {
+    pub fn new(size: u64, start: u64, target: u64) -> Option<Self> {
+        if size == 0 || start >= size || target >= size {
+            return None;
+        }
+
+        Some(Self {
+            size,
+            position: start,
+            target,
+        })
+    }
+
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    pub fn rotate(&mut self, rotation: Rotation) -> DialStep {
+        let from = self.position;
+        let clicks = rotation.clicks % self.size;
+
+        // Clicks needed before the dial first reaches the target
+        let first_hit = match rotation.direction {
+            Direction::Right => {
+                self.position = self.add(from, clicks);
+                self.sub(self.target, from)
+            }
+            Direction::Left => {
+                self.position = self.sub(from, clicks);
+                self.sub(from, self.target)
+            }
+        };
+        let first_hit = if first_hit == 0 { self.size } else { first_hit };
+
+        let passes = if rotation.clicks >= first_hit {
+            (rotation.clicks - first_hit) / self.size + 1
+        } else {
+            0
+        };
+
+        DialStep {
+            rotation,
+            from,
+            to: self.position,
+            stopped_on_target: self.position == self.target,
+            passes,
+        }
+    }
+
+    // `(a + b) % size` for `a, b < size`, without overflowing for sizes
+    // past `u64::MAX / 2`
+    fn add(&self, a: u64, b: u64) -> u64 {
+        if b < self.size - a {
+            a + b
+        } else {
+            b - (self.size - a)
+        }
+    }
+
+    // `(a - b) % size` for `a, b < size`
+    fn sub(&self, a: u64, b: u64) -> u64 {
+        if a >= b { a - b } else { self.size - (b - a) }
+    }
+
+    pub fn trace(&mut self, rotations: &[Rotation]) -> Vec<DialStep> {
+        rotations
+            .iter()
+            .map(|&rotation| self.rotate(rotation))
+            .collect()
+    }
+}
+
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{direction}{}", self.clicks)
+    }
+}
+
+impl fmt::Display for DialStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {} (passes: {}{})",
+            self.rotation,
+            self.from,
+            self.to,
+            self.passes,
+            if self.stopped_on_target {
+                ", stopped"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn simulate(size: u64, start: u64, target: u64, rotation: Rotation) -> (u64, u64) {
+        let mut position = start;
+        let mut passes = 0;
+        for _ in 0..rotation.clicks {
+            position = match rotation.direction {
+                Direction::Right => (position + 1) % size,
+                Direction::Left => (position + size - 1) % size,
+            };
+            if position == target {
+                passes += 1;
+            }
+        }
+
+        (position, passes)
+    }
+
+    #[test]
+    fn test_rotate_matches_simulation() {
+        for size in 1..6 {
+            for start in 0..size {
+                for target in 0..size {
+                    for clicks in 0..20 {
+                        for direction in [Direction::Left, Direction::Right] {
+                            let rotation = Rotation { direction, clicks };
+                            let mut dial = Dial::new(size, start, target).unwrap();
+                            let step = dial.rotate(rotation);
+
+                            assert_eq!(
+                                simulate(size, start, target, rotation),
+                                (step.to, step.passes)
+                            );
+                            assert_eq!(step.to == target, step.stopped_on_target);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_dial() {
+        let mut dial = Dial::new(u64::MAX, u64::MAX - 2, 0).unwrap();
+
+        let step = dial.rotate(Rotation {
+            direction: Direction::Right,
+            clicks: 5,
+        });
+        assert_eq!((3, 1), (step.to, step.passes));
+
+        let step = dial.rotate(Rotation {
+            direction: Direction::Left,
+            clicks: 6,
+        });
+        assert_eq!((u64::MAX - 3, 1), (step.to, step.passes));
+
+        let step = dial.rotate(Rotation {
+            direction: Direction::Left,
+            clicks: u64::MAX,
+        });
+        assert_eq!((u64::MAX - 3, 1), (step.to, step.passes));
+    }
+
+    #[test]
+    fn test_trace() {
+        let mut dial = Dial::new(100, 50, 0).unwrap();
+        let rotations = [
+            Rotation {
+                direction: Direction::Left,
+                clicks: 68,
+            },
+            Rotation {
+                direction: Direction::Right,
+                clicks: 48,
+            },
+            Rotation {
+                direction: Direction::Left,
+                clicks: 250,
+            },
+        ];
+
+        let trace = dial.trace(&rotations);
+
+        assert_eq!(
+            vec![82, 30, 80],
+            trace.iter().map(|s| s.to).collect::<Vec<_>>()
+        );
+        assert_eq!("L68: 50 -> 82 (passes: 1)", trace[0].to_string());
+        assert_eq!(3, trace[2].passes);
+        assert_eq!(80, dial.position());
+        assert!(Dial::new(100, 100, 0).is_none());
+    }
+}
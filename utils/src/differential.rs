@@ -0,0 +1,138 @@
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::generate;
+
+// Runs `solve` on `cases` generated inputs for `day`. It returns the answers
+// of the fast and the reference solver, or `None` when the input is rejected.
+//
+// A case fails when the answers differ or a solver panics. Its input is then
+// shrunk, first by regenerating it from the same seed with a smaller `size`
+// and then, if `drop_lines` is set, by removing lines for as long as it keeps
+// failing. The error shows the smallest failing input found.
+pub fn check<A: PartialEq + Debug>(
+    day: u32,
+    cases: u64,
+    (size, scale): (usize, u64),
+    drop_lines: bool,
+    solve: impl Fn(&str) -> Option<(A, A)>,
+) -> Result<(), String> {
+    let generate = |seed, size| generate::generate(day, seed, Some(size), Some(scale));
+    for seed in 0..cases {
+        let input = generate(seed, size)?;
+        if failure(&input, &solve).is_none() {
+            continue;
+        }
+
+        let mut size = size;
+        while let Some(smaller) = [size / 2, size - 1]
+            .into_iter()
+            .filter(|&smaller| smaller > 0 && smaller < size)
+            .find(|&smaller| {
+                generate(seed, smaller).is_ok_and(|input| failure(&input, &solve).is_some())
+            })
+        {
+            size = smaller;
+        }
+
+        let mut input = generate(seed, size)?;
+        if drop_lines {
+            input = shrink_lines(&input, |input| failure(input, &solve).is_some());
+        }
+        let reason = failure(&input, &solve).unwrap_or_default();
+
+        return Err(format!(
+            "Day {day}, seed {seed}: {reason} on input\n{input}"
+        ));
+    }
+
+    Ok(())
+}
+
+fn failure<A: PartialEq + Debug>(
+    input: &str,
+    solve: impl Fn(&str) -> Option<(A, A)>,
+) -> Option<String> {
+    match panic::catch_unwind(AssertUnwindSafe(|| solve(input))) {
+        Ok(Some((fast, reference))) if fast != reference => Some(format!(
+            "fast solver answered {fast:?} but reference answered {reference:?}"
+        )),
+        Ok(_) => None,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            Some(format!("solver panicked: {message}"))
+        }
+    }
+}
+
+// Removes ever smaller chunks of lines while `fails` keeps holding
+pub fn shrink_lines(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let render =
+        |lines: &[&str]| -> String { lines.iter().map(|line| format!("{line}\n")).collect() };
+    let mut lines: Vec<&str> = input.lines().collect();
+
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate: Vec<&str> = [&lines[..start], &lines[end..]].concat();
+            if !candidate.is_empty() && fails(&render(&candidate)) {
+                lines = candidate;
+            } else {
+                start = end;
+            }
+        }
+        chunk /= 2;
+    }
+
+    render(&lines)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_shrink_lines() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n";
+        let fails = |input: &str| input.contains('3') && input.contains('6');
+
+        assert_eq!("3\n6\n", shrink_lines(input, fails));
+    }
+
+    #[test]
+    fn test_check() {
+        let sum = |input: &str| -> u64 { crate::get_ints::<u64>(input).iter().sum() };
+        assert_eq!(
+            Ok(()),
+            check(1, 5, (20, 100), true, |input| Some((
+                sum(input),
+                sum(input)
+            )))
+        );
+
+        // Wrong once a single rotation goes past 90
+        let err = check(1, 5, (20, 100), true, |input| {
+            let wrong = crate::get_ints::<u64>(input)
+                .iter()
+                .filter(|&&n| n <= 90)
+                .sum();
+            Some((wrong, sum(input)))
+        })
+        .unwrap_err();
+        assert_eq!(1, err.lines().skip(1).count(), "{err}");
+        assert!(err.contains("fast solver answered 0 but reference answered"));
+
+        let err = check(1, 5, (20, 100), true, |input| -> Option<(u64, u64)> {
+            assert!(input.len() < 10, "too long");
+            None
+        })
+        .unwrap_err();
+        assert!(err.contains("solver panicked: too long"), "{err}");
+    }
+}
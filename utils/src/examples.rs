@@ -0,0 +1,106 @@
+use std::fmt::Display;
+
+// Expected answers sit next to each example as `inputs/dayN_test.answers`,
+// one `part: answer` per line:
+//
+//     part_one: 3
+//     part_two: 6
+pub fn expected_answer<'a>(answers: &'a str, part: &str) -> Option<&'a str> {
+    answers
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(key, _)| key.trim() == part)
+        .map(|(_, answer)| answer.trim())
+}
+
+pub fn check_answer(day: &str, answers: &str, part: &str, answer: impl Display) {
+    let Some(expected) = expected_answer(answers, part) else {
+        panic!("No expected answer for {part} in {day}_test.answers");
+    };
+
+    assert_eq!(expected, answer.to_string(), "{day} {part}");
+}
+
+// Generates `test_part_one` and `test_part_two`, running both parts on
+// `inputs/dayN_test.txt` and checking them against `dayN_test.answers`.
+// With `parse`, each part gets `&mut` to the parsed input; without it, each
+// part gets the raw `&str`.
+//
+//     utils::example_tests! {
+//         day: 1,
+//         parse: |input| utils::get_lines(input),
+//         part_one: |lines| part_one(lines).unwrap(),
+//         part_two: |lines| part_two(lines).unwrap(),
+//     }
+#[macro_export]
+macro_rules! example_tests {
+    (
+        day: $day:literal,
+        parse: |$input:ident| $parse:expr,
+        part_one: |$one:ident| $part_one:expr,
+        part_two: |$two:ident| $part_two:expr $(,)?
+    ) => {
+        $crate::example_tests!(@part $day, test_part_one, "part_one", |$input| {
+            let mut parsed = $parse;
+            let $one = &mut parsed;
+            $part_one
+        });
+        $crate::example_tests!(@part $day, test_part_two, "part_two", |$input| {
+            let mut parsed = $parse;
+            let $two = &mut parsed;
+            $part_two
+        });
+    };
+    (
+        day: $day:literal,
+        part_one: |$one:ident| $part_one:expr,
+        part_two: |$two:ident| $part_two:expr $(,)?
+    ) => {
+        $crate::example_tests!(@part $day, test_part_one, "part_one", |$one| $part_one);
+        $crate::example_tests!(@part $day, test_part_two, "part_two", |$two| $part_two);
+    };
+    (@part $day:literal, $name:ident, $part:literal, |$input:ident| $answer:expr) => {
+        #[test]
+        fn $name() {
+            let $input: &str = include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../inputs/day",
+                stringify!($day),
+                "_test.txt"
+            ));
+            let answers = include_str!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/../inputs/day",
+                stringify!($day),
+                "_test.answers"
+            ));
+
+            $crate::examples::check_answer(
+                concat!("day", stringify!($day)),
+                answers,
+                $part,
+                $answer,
+            );
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_expected_answer() {
+        let answers = "part_one: 3\n  part_two :  3121910778619 \n";
+
+        assert_eq!(Some("3"), expected_answer(answers, "part_one"));
+        assert_eq!(Some("3121910778619"), expected_answer(answers, "part_two"));
+        assert_eq!(None, expected_answer(answers, "part_three"));
+    }
+
+    #[test]
+    #[should_panic(expected = "No expected answer for part_two in day1_test.answers")]
+    fn test_missing_answer() {
+        check_answer("day1", "part_one: 3", "part_two", 6);
+    }
+}
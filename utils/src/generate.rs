@@ -0,0 +1,365 @@
+use std::collections::BTreeSet;
+
+// SplitMix64. Tiny, and unlike library generators its output is fixed for
+// good, so a seed keeps producing the same input.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // Uniform in the inclusive range `(low, high)`
+    pub fn range(&mut self, (low, high): (u64, u64)) -> u64 {
+        assert!(low <= high, "Empty range {low}-{high}");
+        match (high - low).checked_add(1) {
+            Some(span) => low + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        self.range((0, len as u64 - 1)) as usize
+    }
+
+    pub fn chance(&mut self, percent: u64) -> bool {
+        self.range((1, 100)) <= percent
+    }
+
+    // `count` distinct values of the inclusive range `(low, high)`, sorted
+    pub fn distinct(&mut self, count: usize, (low, high): (u64, u64)) -> Vec<u64> {
+        assert!(
+            (count as u64) <= high - low + 1,
+            "Cannot pick {count} distinct values from {low}-{high}"
+        );
+        let mut values = BTreeSet::new();
+        while values.len() < count {
+            values.insert(self.range((low, high)));
+        }
+
+        values.into_iter().collect()
+    }
+}
+
+// Every generator takes two knobs. `size` is how many lines, items or rows to
+// produce and `scale` how large each of them gets:
+//
+//     day  size                 scale
+//     1    rotations            largest distance
+//     2    ID ranges            largest range span
+//     3    battery banks        batteries per bank
+//     4    grid rows            grid columns
+//     5    IDs                  largest ID
+//     6    problems             numbers per problem
+//     7    manifold rows        manifold columns
+//     8    junction boxes       largest coordinate
+//     9    polygon corners      largest coordinate
+//     10   machines             most indicator lights
+pub const DEFAULTS: [(usize, u64); 10] = [
+    (4000, 1000),
+    (40, 100_000),
+    (200, 100),
+    (140, 140),
+    (1000, 500_000_000_000_000),
+    (1000, 4),
+    (142, 141),
+    (1000, 100_000),
+    (500, 100_000),
+    (160, 10),
+];
+
+pub fn generate(
+    day: u32,
+    seed: u64,
+    size: Option<usize>,
+    scale: Option<u64>,
+) -> Result<String, String> {
+    let &(default_size, default_scale) = DEFAULTS
+        .get((day as usize).wrapping_sub(1))
+        .ok_or_else(|| format!("There is no generator for day {day}"))?;
+    let size = size.unwrap_or(default_size);
+    let scale = scale.unwrap_or(default_scale);
+
+    let mut rng = Rng::new(seed);
+    let generate = match day {
+        1 => rotations,
+        2 => id_ranges,
+        3 => battery_banks,
+        4 => paper_grid,
+        5 => inventory,
+        6 => worksheet,
+        7 => manifold,
+        8 => junction_boxes,
+        9 => polygon,
+        _ => machines,
+    };
+
+    Ok(generate(&mut rng, size, scale))
+}
+
+pub fn rotations(rng: &mut Rng, size: usize, scale: u64) -> String {
+    (0..size)
+        .map(|_| {
+            let direction = if rng.chance(50) { 'L' } else { 'R' };
+            format!("{direction}{}\n", rng.range((1, scale.max(1))))
+        })
+        .collect()
+}
+
+// Disjoint ranges of up to ten digit IDs, in random order on one line
+pub fn id_ranges(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let starts = rng.distinct(size, (1, 9_999_999_999));
+    let mut ranges: Vec<String> = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &low)| {
+            let limit = starts.get(i + 1).map_or(9_999_999_999, |&next| next - 1);
+            let high = (low + rng.range((0, scale))).min(limit);
+            format!("{low}-{high}")
+        })
+        .collect();
+    for i in (1..ranges.len()).rev() {
+        ranges.swap(i, rng.index(i + 1));
+    }
+
+    ranges.join(",") + "\n"
+}
+
+// At least twelve batteries per bank, as part two turns on twelve of them
+pub fn battery_banks(rng: &mut Rng, size: usize, scale: u64) -> String {
+    (0..size)
+        .map(|_| {
+            let mut bank: String = (0..scale.max(12))
+                .map(|_| char::from(b'0' + rng.range((1, 9)) as u8))
+                .collect();
+            bank.push('\n');
+            bank
+        })
+        .collect()
+}
+
+pub fn paper_grid(rng: &mut Rng, size: usize, scale: u64) -> String {
+    (0..size)
+        .map(|_| {
+            let mut row: String = (0..scale)
+                .map(|_| if rng.chance(60) { '@' } else { '.' })
+                .collect();
+            row.push('\n');
+            row
+        })
+        .collect()
+}
+
+// Fresh ID ranges, which overlap freely, followed by the available IDs
+pub fn inventory(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let scale = scale.max(1);
+    let mut out = String::new();
+    for _ in 0..size / 5 + 1 {
+        let low = rng.range((1, scale));
+        let high = (low + rng.range((0, scale / 100))).min(scale);
+        out.push_str(&format!("{low}-{high}\n"));
+    }
+    out.push('\n');
+    for _ in 0..size {
+        out.push_str(&format!("{}\n", rng.range((1, scale))));
+    }
+
+    out
+}
+
+// Problems are written in columns separated by a blank column, with their
+// numbers aligned to either side and the operation under the first digit
+pub fn worksheet(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let rows = scale.max(1) as usize;
+    let mut lines = vec![String::new(); rows + 1];
+    for problem in 0..size {
+        let numbers: Vec<String> = (0..rows)
+            .map(|_| {
+                let digits = rng.range((1, 4)) as u32;
+                rng.range((10u64.pow(digits - 1), 10u64.pow(digits) - 1))
+                    .to_string()
+            })
+            .collect();
+        let width = numbers.iter().map(String::len).max().unwrap_or(1);
+        let left_aligned = rng.chance(50);
+
+        let separator = if problem == 0 { "" } else { " " };
+        for (line, number) in lines.iter_mut().zip(&numbers) {
+            line.push_str(separator);
+            if left_aligned {
+                line.push_str(&format!("{number:<width$}"));
+            } else {
+                line.push_str(&format!("{number:>width$}"));
+            }
+        }
+        let operation = if rng.chance(50) { '+' } else { '*' };
+        lines[rows].push_str(separator);
+        lines[rows].push_str(&format!("{operation:<width$}"));
+    }
+
+    lines.iter().map(|line| format!("{line}\n")).collect()
+}
+
+// The beam enters at the top centre. Splitters sit on every other row, never
+// at the edges and never next to each other.
+pub fn manifold(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let cols = scale.max(3) as usize;
+    let mut grid = vec![vec!['.'; cols]; size.max(1)];
+    grid[0][cols / 2] = 'S';
+    for row in grid.iter_mut().skip(2).step_by(2) {
+        for col in 1..cols - 1 {
+            if row[col - 1] != '^' && rng.chance(30) {
+                row[col] = '^';
+            }
+        }
+    }
+
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
+
+pub fn junction_boxes(rng: &mut Rng, size: usize, scale: u64) -> String {
+    (0..size)
+        .map(|_| {
+            let [x, y, z] = [(); 3].map(|_| rng.range((0, scale)));
+            format!("{x},{y},{z}\n")
+        })
+        .collect()
+}
+
+// A simple rectilinear polygon, listed corner by corner. It is made of
+// vertical strips between increasing x coordinates, each overlapping the
+// previous one vertically, so its outline never crosses itself.
+pub fn polygon(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let strips = (size / 4).max(1);
+    let scale = scale.max(strips as u64 + 3);
+    let xs = rng.distinct(strips + 1, (0, scale));
+
+    // Tops stay above 1 and bottoms below `scale - 1`, which always leaves
+    // the next strip a choice different from the current one
+    let mut tops = vec![rng.range((2, scale))];
+    let mut bottoms = vec![rng.range((0, (tops[0] - 1).min(scale - 2)))];
+    while tops.len() < strips {
+        let (top, bottom) = (tops[tops.len() - 1], bottoms[bottoms.len() - 1]);
+        let next_top = rng.range(((bottom + 1).max(2), scale));
+        let next_bottom = rng.range((0, (next_top.min(top) - 1).min(scale - 2)));
+        if next_top != top && next_bottom != bottom {
+            tops.push(next_top);
+            bottoms.push(next_bottom);
+        }
+    }
+
+    let mut corners = vec![];
+    for (i, &top) in tops.iter().enumerate() {
+        corners.push((xs[i], top));
+        corners.push((xs[i + 1], top));
+    }
+    for (i, &bottom) in bottoms.iter().enumerate().rev() {
+        corners.push((xs[i + 1], bottom));
+        corners.push((xs[i], bottom));
+    }
+
+    corners.iter().map(|(x, y)| format!("{x},{y}\n")).collect()
+}
+
+// Machines are built from a secret number of presses per button, so the
+// lights and joltages can always be reached.
+pub fn machines(rng: &mut Rng, size: usize, scale: u64) -> String {
+    let max_lights = scale.clamp(1, 16);
+    (0..size)
+        .map(|_| {
+            let lights = rng.range((1, max_lights)) as usize;
+            // Day ten rejects machines with more than 16 buttons
+            let max_buttons = (lights as u64 + 3).min(16);
+            let mut buttons: Vec<Vec<usize>> = (0..rng.range((1, max_buttons)))
+                .map(|_| {
+                    let mut button: Vec<usize> = (0..lights).filter(|_| rng.chance(40)).collect();
+                    if button.is_empty() {
+                        button.push(rng.index(lights));
+                    }
+                    button
+                })
+                .collect();
+            for light in 0..lights {
+                if !buttons.iter().any(|button| button.contains(&light)) {
+                    let button = rng.index(buttons.len());
+                    buttons[button].push(light);
+                    buttons[button].sort_unstable();
+                }
+            }
+
+            let mut on = vec![false; lights];
+            let mut joltage = vec![0; lights];
+            for button in &buttons {
+                let toggled = rng.chance(50);
+                let presses = rng.range((0, 20));
+                for &light in button {
+                    on[light] ^= toggled;
+                    joltage[light] += presses;
+                }
+            }
+
+            let on: String = on.iter().map(|&on| if on { '#' } else { '.' }).collect();
+            let buttons: Vec<String> = buttons
+                .iter()
+                .map(|button| format!("({})", join(button)))
+                .collect();
+            format!("[{on}] {} {{{}}}\n", buttons.join(" "), join(&joltage))
+        })
+        .collect()
+}
+
+fn join<T: ToString>(values: &[T]) -> String {
+    values
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=10 {
+            let input = generate(day, 7, Some(20), None).unwrap();
+            assert_eq!(input, generate(day, 7, Some(20), None).unwrap());
+            assert_ne!(input, generate(day, 8, Some(20), None).unwrap());
+        }
+        assert!(generate(11, 0, None, None).is_err());
+    }
+
+    #[test]
+    fn test_polygon_is_rectilinear() {
+        let input = polygon(&mut Rng::new(3), 40, 50);
+        let corners: Vec<Vec<u64>> = input.lines().map(crate::get_ints).collect();
+
+        assert_eq!(40, corners.len());
+        for (i, a) in corners.iter().enumerate() {
+            let b = &corners[(i + 1) % corners.len()];
+            assert!((a[0] == b[0]) != (a[1] == b[1]), "{a:?} -> {b:?}");
+        }
+    }
+
+    #[test]
+    fn test_worksheet_columns() {
+        let input = worksheet(&mut Rng::new(1), 5, 3);
+        let lines: Vec<&str> = input.lines().collect();
+
+        assert_eq!(4, lines.len());
+        assert!(lines.iter().all(|line| line.len() == lines[0].len()));
+        assert_eq!(5, lines[3].split_whitespace().count());
+    }
+}
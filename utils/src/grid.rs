@@ -1,49 +1,73 @@
-#[derive(Debug)]
-pub struct Grid<T>
-where
-    T: Copy + Ord
-{
+use std::collections::HashSet;
+use std::fmt;
+use std::mem;
+use std::ops::{Index, IndexMut};
+
+use crate::grid_view::GridView;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
     rows: usize,
     cols: usize,
     data: Vec<T>,
 }
 
-impl<T: Copy + Ord> Grid<T> {
-    pub fn fill(rows: usize, cols:usize, fill: T) -> Self {
+impl<T: Clone> Grid<T> {
+    pub fn fill(rows: usize, cols: usize, fill: T) -> Self {
         let data = vec![fill; rows * cols];
 
-        Self {
-            rows,
-            cols,
-            data
-        }
+        Self { rows, cols, data }
     }
 
-    pub fn new(data: Vec<T>, rows: usize) -> Self {
-        let cols = data.len() / rows;
+    // Returns the newly set element, or `None` if the position is out of bounds.
+    pub fn set(&mut self, row: usize, col: usize, elem: T) -> Option<T> {
+        let cell = self.get_mut(row, col)?;
+        *cell = elem.clone();
+        Some(elem)
+    }
 
-        Self { rows, cols, data }
+    pub fn transpose(&self) -> Self {
+        self.view().transpose().to_grid()
     }
 
-    pub fn get(&self, row: usize, col: usize) -> Option<T> {
-        if row < self.rows && col < self.cols {
-            return Some(self.data[row * self.cols + col]);
+    pub fn rotate_cw(&self) -> Self {
+        self.view().rotate_cw().to_grid()
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.view().rotate_ccw().to_grid()
+    }
+
+    pub fn flip_h(&self) -> Self {
+        self.view().flip_h().to_grid()
+    }
+
+    pub fn flip_v(&self) -> Self {
+        self.view().flip_v().to_grid()
+    }
+
+    pub fn sub_grid(&self, rows: (usize, usize), cols: (usize, usize)) -> Option<Self> {
+        self.view().sub_view(rows, cols).map(|view| view.to_grid())
+    }
+
+    pub fn pad(&self, width: usize, border: T) -> Self {
+        let mut padded = Self::fill(self.rows + 2 * width, self.cols + 2 * width, border);
+        for i in 0..self.rows {
+            padded.get_row_mut(i + width)[width..width + self.cols].clone_from_slice(self.get_row(i));
         }
-        None
+
+        padded
     }
+}
 
-    pub fn set(&mut self, row: usize, col: usize, elem: T) -> Option<T>  {
-      if row < self.rows && col < self.cols {
-        self.data[row * self.cols + col] = elem;
-        Some(elem)
-      } else {
-        None
-      }
+impl<T: Copy> Grid<T> {
+    pub fn get(&self, row: usize, col: usize) -> Option<T> {
+        self.get_ref(row, col).copied()
     }
 
     pub fn get_grid_range(&self, rows: (usize, usize), cols: (usize, usize)) -> Vec<T> {
-        if rows.1 < self.rows && rows.0 < rows.1 && cols.1 < self.cols && cols.0 < cols.1 {
-            let mut range = Vec::with_capacity((rows.1 - rows.0) + (cols.1 - cols.0));
+        if rows.1 < self.rows && rows.0 <= rows.1 && cols.1 < self.cols && cols.0 <= cols.1 {
+            let mut range = Vec::with_capacity((rows.1 - rows.0 + 1) * (cols.1 - cols.0 + 1));
             for i in rows.0..=rows.1 {
                 for j in cols.0..=cols.1 {
                     match self.get(i, j) {
@@ -57,17 +81,86 @@ impl<T: Copy + Ord> Grid<T> {
 
         vec![]
     }
+}
+
+impl<T> Grid<T> {
+    // Panics unless `data` splits evenly into `rows` rows.
+    pub fn new(data: Vec<T>, rows: usize) -> Self {
+        let cols = data.len().checked_div(rows).unwrap_or(0);
+        assert_eq!(
+            rows * cols,
+            data.len(),
+            "{} cells don't split into {rows} rows",
+            data.len()
+        );
+
+        Self { rows, cols, data }
+    }
+
+    // Fails with the offending (1-based) line if the rows differ in length.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, String> {
+        let mut data = vec![];
+        let mut cols = None;
+        let mut num_rows = 0;
 
-    pub fn get_row(&self, row_idx: usize) -> Vec<T> {
+        for (idx, row) in rows.into_iter().enumerate() {
+            let expected = *cols.get_or_insert(row.len());
+            if row.len() != expected {
+                return Err(format!(
+                    "Line {}: expected {expected} columns, found {}",
+                    idx + 1,
+                    row.len()
+                ));
+            }
+
+            data.extend(row);
+            num_rows += 1;
+        }
+
+        Ok(Self::from_parts(data, num_rows, cols.unwrap_or(0)))
+    }
+
+    pub(crate) fn from_parts(data: Vec<T>, rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data }
+    }
+
+    pub fn view(&self) -> GridView<'_, T> {
+        GridView::new(self)
+    }
+
+    pub fn get_ref(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            return Some(&self.data[row * self.cols + col]);
+        }
+        None
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row < self.rows && col < self.cols {
+            return Some(&mut self.data[row * self.cols + col]);
+        }
+        None
+    }
+
+    // Returns the previous value, or `None` if the position is out of bounds.
+    pub fn replace(&mut self, row: usize, col: usize, elem: T) -> Option<T> {
+        self.get_mut(row, col).map(|cell| mem::replace(cell, elem))
+    }
+
+    pub fn get_row(&self, row_idx: usize) -> &[T] {
         if row_idx >= self.rows {
-            return vec![];
+            return &[];
         }
 
-        let mut row = vec![];
-        for j in 0..self.cols {
-            self.get(row_idx, j).map(|elem| row.push(elem));
+        &self.data[row_idx * self.cols..(row_idx + 1) * self.cols]
+    }
+
+    pub fn get_row_mut(&mut self, row_idx: usize) -> &mut [T] {
+        if row_idx >= self.rows {
+            return &mut [];
         }
-        row
+
+        &mut self.data[row_idx * self.cols..(row_idx + 1) * self.cols]
     }
 
     pub fn rows(&self) -> usize {
@@ -78,14 +171,267 @@ impl<T: Copy + Ord> Grid<T> {
         self.cols
     }
 
-    pub fn first_pos_of(&self, elem: T) -> Option<(usize, usize)> {
+    pub fn render(&self, to_char: impl Fn(&T) -> char) -> String {
+        self.render_overlay(to_char, &HashSet::new(), ' ')
+    }
+
+    // Like `render`, but cells in `highlights` are drawn as `mark`.
+    pub fn render_overlay(
+        &self,
+        to_char: impl Fn(&T) -> char,
+        highlights: &HashSet<(usize, usize)>,
+        mark: char,
+    ) -> String {
+        let mut out = String::with_capacity(self.rows * (self.cols + 1));
         for i in 0..self.rows {
-            for j in 0..self.cols {
-                if self.data[i * self.rows + j] == elem {
-                    return Some((i, j))
+            for (j, elem) in self.get_row(i).iter().enumerate() {
+                if highlights.contains(&(i, j)) {
+                    out.push(mark);
+                } else {
+                    out.push(to_char(elem));
                 }
             }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    pub fn first_pos_of(&self, elem: T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.find(|e| *e == elem)
+    }
+
+    pub fn positions_of(&self, elem: T) -> impl Iterator<Item = (usize, usize)> + '_
+    where
+        T: PartialEq,
+    {
+        self.enumerate_row_major()
+            .filter(move |(_, e)| **e == elem)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<(usize, usize)> {
+        self.enumerate_row_major()
+            .find(|(_, e)| predicate(e))
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn count(&self, predicate: impl Fn(&T) -> bool) -> usize {
+        self.data.iter().filter(|e| predicate(e)).count()
+    }
+
+    pub fn enumerate_row_major(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(idx, e)| ((idx / cols, idx % cols), e))
+    }
+
+    pub fn enumerate_col_major(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        (0..self.cols).flat_map(move |j| self.column(j).enumerate().map(move |(i, e)| ((i, j), e)))
+    }
+
+    // Empty if `col_idx` is out of bounds.
+    pub fn column(&self, col_idx: usize) -> impl Iterator<Item = &T> + '_ {
+        let rows = if col_idx < self.cols { self.rows } else { 0 };
+        (0..rows).map(move |i| &self.data[i * self.cols + col_idx])
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get_ref(row, col) {
+            Some(elem) => elem,
+            None => panic!(
+                "Grid index ({row}, {col}) out of bounds for {}x{} grid",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(row, col) {
+            Some(elem) => elem,
+            None => panic!("Grid index ({row}, {col}) out of bounds for {rows}x{cols} grid"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::input_helpers::get_raw_grid;
+
+    #[test]
+    fn test_index() {
+        let mut grid = Grid::new(vec![0.5, 1.5, 2.5, 3.5, 4.5, 5.5], 2);
+
+        assert_eq!(3, grid.cols());
+        assert_eq!(5.5, grid[(1, 2)]);
+
+        grid[(0, 1)] = -1.0;
+        assert_eq!(Some(&-1.0), grid.get_ref(0, 1));
+        assert_eq!(None, grid.get_ref(2, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::fill(2, 3, 0u8);
+        let _ = grid[(0, 3)];
+    }
+
+    #[test]
+    #[should_panic(expected = "5 cells don't split into 2 rows")]
+    fn test_new_uneven() {
+        Grid::new(vec![1, 2, 3, 4, 5], 2);
+    }
+
+    #[test]
+    fn test_rows_and_set() {
+        let mut grid: Grid<Vec<u8>> = Grid::fill(2, 2, vec![]);
+
+        grid.get_mut(1, 0).unwrap().push(7);
+        assert_eq!(Some(vec![]), grid.replace(0, 1, vec![1, 2]));
+        assert_eq!(Some(vec![3]), grid.set(1, 1, vec![3]));
+        assert_eq!(None, grid.set(2, 0, vec![]));
+
+        assert_eq!(&[vec![], vec![1, 2]], grid.get_row(0));
+        assert_eq!(&[vec![7], vec![3]], grid.get_row(1));
+        assert!(grid.get_row(2).is_empty());
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let grid = get_raw_grid("..@.\n@ .S\n").unwrap();
+
+        assert_eq!("..@.\n@ .S\n", grid.to_string());
+        assert_eq!(Ok(grid.clone()), get_raw_grid(&grid.to_string()));
+    }
+
+    #[test]
+    fn test_render_overlay() {
+        let grid = Grid::new(vec![0u8, 1, 1, 0], 2);
+        let highlights = HashSet::from([(1, 0)]);
+
+        assert_eq!(".#\n#.\n", grid.render(|&b| if b == 1 { '#' } else { '.' }));
+        assert_eq!(
+            ".#\nx.\n",
+            grid.render_overlay(|&b| if b == 1 { '#' } else { '.' }, &highlights, 'x')
+        );
+    }
+
+    #[test]
+    fn test_search_non_square() {
+        // S . .
+        // . # S
+        let grid = Grid::new(vec!['S', '.', '.', '.', '#', 'S'], 2);
+
+        assert_eq!(Some((0, 0)), grid.first_pos_of('S'));
+        assert_eq!(Some((1, 1)), grid.first_pos_of('#'));
+        assert_eq!(None, grid.first_pos_of('x'));
+        assert_eq!(vec![(0, 0), (1, 2)], grid.positions_of('S').collect::<Vec<_>>());
+        assert_eq!(Some((0, 1)), grid.find(|&c| c == '.'));
+        assert_eq!(3, grid.count(|&c| c == '.'));
+
+        let tall = grid.transpose();
+        assert_eq!(Some((1, 1)), tall.first_pos_of('#'));
+        assert_eq!(vec![(0, 0), (2, 1)], tall.positions_of('S').collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_enumerate_and_column() {
+        let grid = Grid::new(vec![1, 2, 3, 4, 5, 6], 2);
+
+        let row_major: Vec<_> = grid.enumerate_row_major().map(|(pos, &e)| (pos, e)).collect();
+        assert_eq!(((0, 2), 3), row_major[2]);
+        assert_eq!(((1, 0), 4), row_major[3]);
+
+        let col_major: Vec<_> = grid.enumerate_col_major().map(|(pos, &e)| (pos, e)).collect();
+        assert_eq!(
+            vec![((0, 0), 1), ((1, 0), 4), ((0, 1), 2), ((1, 1), 5), ((0, 2), 3), ((1, 2), 6)],
+            col_major
+        );
+
+        assert_eq!(vec![&3, &6], grid.column(2).collect::<Vec<_>>());
+        assert_eq!(0, grid.column(3).count());
+    }
+
+    // Grids of up to 8x8 cells holding few distinct values, so searches hit
+    fn grids() -> impl Strategy<Value = Grid<u8>> {
+        (1..=8usize, 1..=8usize).prop_flat_map(|(rows, cols)| {
+            vec(0..4u8, rows * cols).prop_map(move |data| Grid::new(data, rows))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_get_round_trip(
+            mut grid in grids(),
+            row in 0..10usize,
+            col in 0..10usize,
+            elem: u8,
+        ) {
+            let before = grid.clone();
+            let previous = grid.replace(row, col, elem);
+
+            prop_assert_eq!(previous, before.get(row, col));
+            if let Some(previous) = previous {
+                prop_assert_eq!(Some(elem), grid.get(row, col));
+                prop_assert_eq!(Some(previous), grid.set(row, col, previous));
+            }
+            prop_assert_eq!(before, grid);
+        }
+
+        #[test]
+        fn prop_get_grid_range(grid in grids(), rows: (u8, u8), cols: (u8, u8)) {
+            let rows = (rows.0 as usize % 10, rows.1 as usize % 10);
+            let cols = (cols.0 as usize % 10, cols.1 as usize % 10);
+            let range = grid.get_grid_range(rows, cols);
+
+            let in_bounds = rows.0 <= rows.1
+                && rows.1 < grid.rows()
+                && cols.0 <= cols.1
+                && cols.1 < grid.cols();
+            let expected: Vec<u8> = if in_bounds {
+                (rows.0..=rows.1)
+                    .flat_map(|i| (cols.0..=cols.1).map(move |j| (i, j)))
+                    .map(|(i, j)| grid[(i, j)])
+                    .collect()
+            } else {
+                vec![]
+            };
+            prop_assert_eq!(&expected, &range);
+            // Collected in one allocation of exactly the right size
+            prop_assert_eq!(expected.len(), range.capacity());
+        }
+
+        #[test]
+        fn prop_first_pos_of(grid in grids(), elem in 0..4u8) {
+            let expected = (0..grid.rows())
+                .flat_map(|i| (0..grid.cols()).map(move |j| (i, j)))
+                .find(|&pos| grid[pos] == elem);
+
+            prop_assert_eq!(expected, grid.first_pos_of(elem));
+            prop_assert_eq!(expected, grid.positions_of(elem).next());
         }
-        None
     }
 }
@@ -0,0 +1,201 @@
+use std::ops::Index;
+
+use crate::grid::Grid;
+
+// A borrowed, possibly transformed window onto a `Grid`. View position
+// `(r, c)` maps to `origin + r * row_step + c * col_step` in the grid, so
+// transposes, rotations, flips and sub-views only adjust the mapping.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    grid: &'a Grid<T>,
+    rows: usize,
+    cols: usize,
+    origin: (isize, isize),
+    row_step: (isize, isize),
+    col_step: (isize, isize),
+}
+
+impl<T> Clone for GridView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for GridView<'_, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    pub fn new(grid: &'a Grid<T>) -> Self {
+        Self {
+            grid,
+            rows: grid.rows(),
+            cols: grid.cols(),
+            origin: (0, 0),
+            row_step: (1, 0),
+            col_step: (0, 1),
+        }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn get_ref(&self, row: usize, col: usize) -> Option<&'a T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+
+        let (r, c) = self.offset(row, col);
+        self.grid.get_ref(r as usize, c as usize)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<T>
+    where
+        T: Copy,
+    {
+        self.get_ref(row, col).copied()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            row_step: self.col_step,
+            col_step: self.row_step,
+            ..*self
+        }
+    }
+
+    pub fn flip_v(&self) -> Self {
+        Self {
+            origin: self.offset(self.rows.saturating_sub(1), 0),
+            row_step: (-self.row_step.0, -self.row_step.1),
+            ..*self
+        }
+    }
+
+    pub fn flip_h(&self) -> Self {
+        Self {
+            origin: self.offset(0, self.cols.saturating_sub(1)),
+            col_step: (-self.col_step.0, -self.col_step.1),
+            ..*self
+        }
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.transpose().flip_h()
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.transpose().flip_v()
+    }
+
+    // Inclusive ranges, in the coordinates of this view.
+    pub fn sub_view(&self, rows: (usize, usize), cols: (usize, usize)) -> Option<Self> {
+        if rows.0 > rows.1 || cols.0 > cols.1 || rows.1 >= self.rows || cols.1 >= self.cols {
+            return None;
+        }
+
+        Some(Self {
+            rows: rows.1 - rows.0 + 1,
+            cols: cols.1 - cols.0 + 1,
+            origin: self.offset(rows.0, cols.0),
+            ..*self
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.rows * self.cols);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                data.push(self[(i, j)].clone());
+            }
+        }
+
+        Grid::from_parts(data, self.rows, self.cols)
+    }
+
+    fn offset(&self, row: usize, col: usize) -> (isize, isize) {
+        let (row, col) = (row as isize, col as isize);
+        (
+            self.origin.0 + row * self.row_step.0 + col * self.col_step.0,
+            self.origin.1 + row * self.row_step.1 + col * self.col_step.1,
+        )
+    }
+}
+
+impl<T> Index<(usize, usize)> for GridView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.get_ref(row, col) {
+            Some(elem) => elem,
+            None => panic!(
+                "GridView index ({row}, {col}) out of bounds for {}x{} view",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn sample() -> Grid<u8> {
+        // 1 2 3
+        // 4 5 6
+        Grid::new(vec![1, 2, 3, 4, 5, 6], 2)
+    }
+
+    #[test]
+    fn test_transforms() {
+        let grid = sample();
+
+        assert_eq!(Grid::new(vec![1, 4, 2, 5, 3, 6], 3), grid.transpose());
+        assert_eq!(Grid::new(vec![4, 1, 5, 2, 6, 3], 3), grid.rotate_cw());
+        assert_eq!(Grid::new(vec![3, 6, 2, 5, 1, 4], 3), grid.rotate_ccw());
+        assert_eq!(Grid::new(vec![3, 2, 1, 6, 5, 4], 2), grid.flip_h());
+        assert_eq!(Grid::new(vec![4, 5, 6, 1, 2, 3], 2), grid.flip_v());
+        assert_eq!(grid, grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw());
+    }
+
+    #[test]
+    fn test_composed_views() {
+        let grid = sample();
+        let view = grid.view().rotate_cw().flip_v();
+
+        assert_eq!(3, view.rows());
+        assert_eq!(2, view.cols());
+        // 6 3
+        // 5 2
+        // 4 1
+        assert_eq!(Some(3), view.get(0, 1));
+        assert_eq!(Some(4), view.get(2, 0));
+        assert_eq!(None, view.get(3, 0));
+
+        let sub = view.sub_view((1, 2), (0, 0)).unwrap();
+        assert_eq!(Grid::new(vec![5, 4], 2), sub.to_grid());
+        assert!(view.sub_view((0, 3), (0, 0)).is_none());
+    }
+
+    #[test]
+    fn test_sub_grid_and_pad() {
+        let grid = sample();
+
+        assert_eq!(Some(Grid::new(vec![2, 3, 5, 6], 2)), grid.sub_grid((0, 1), (1, 2)));
+        assert_eq!(None, grid.sub_grid((0, 2), (0, 0)));
+
+        let padded = grid.pad(1, 0);
+        assert_eq!(4, padded.rows());
+        assert_eq!(5, padded.cols());
+        assert_eq!(&[0, 4, 5, 6, 0], padded.get_row(2));
+        assert_eq!(&[0; 5], padded.get_row(3));
+    }
+}
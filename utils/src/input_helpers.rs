@@ -1,11 +1,57 @@
-use std::fs;
-use std::io;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
+use std::iter;
 use std::path::Path;
+use std::str::FromStr;
+
+use flate2::read::MultiGzDecoder;
 
 use crate::grid::Grid;
 
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+// A path of `-` reads stdin. Compressed input is decompressed while reading.
+pub fn open_input(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    let raw: Box<dyn Read> = if path == Path::new("-") {
+        Box::new(io::stdin().lock())
+    } else {
+        Box::new(File::open(path)?)
+    };
+
+    decompress(raw)
+}
+
+// Gzip and zstd input is recognised by its magic bytes, anything else is
+// passed through unchanged.
+pub fn decompress(mut raw: Box<dyn Read>) -> io::Result<Box<dyn BufRead>> {
+    let mut header = Vec::with_capacity(ZSTD_MAGIC.len());
+    raw.by_ref()
+        .take(ZSTD_MAGIC.len() as u64)
+        .read_to_end(&mut header)?;
+    let is_gzip = header.starts_with(&GZIP_MAGIC);
+    let is_zstd = header.starts_with(&ZSTD_MAGIC);
+    let reader = BufReader::new(io::Cursor::new(header).chain(raw));
+
+    if is_gzip {
+        Ok(Box::new(BufReader::new(MultiGzDecoder::new(reader))))
+    } else if is_zstd {
+        Ok(Box::new(BufReader::new(zstd::Decoder::with_buffer(reader)?)))
+    } else {
+        Ok(Box::new(reader))
+    }
+}
+
 pub fn read_file(path: &Path) -> io::Result<String> {
-    Ok(fs::read_to_string(path)?)
+    let mut content = String::new();
+    open_input(path)?.read_to_string(&mut content)?;
+
+    Ok(content)
+}
+
+// Lines are yielded as they are read, without their line endings.
+pub fn read_lines(path: &Path) -> io::Result<impl Iterator<Item = io::Result<String>>> {
+    Ok(open_input(path)?.lines())
 }
 
 pub fn split_whitespace(s: &str) -> Vec<String> {
@@ -16,47 +62,352 @@ pub fn split_whitespace(s: &str) -> Vec<String> {
 
 pub fn get_uints(s: &str) -> Vec<u64> {
     s.split_whitespace()
-        .map(|part| part.parse().ok())
-        .flatten()
+        .filter_map(|part| part.parse().ok())
         .collect()
 }
 
+// Byte spans of every integer in `s`, whatever separates them. A `-` right
+// before a digit is a sign, unless `ranges` is set and it follows a digit.
+fn int_spans(s: &str, ranges: bool) -> Vec<(usize, usize)> {
+    let bytes = s.as_bytes();
+    let mut spans = vec![];
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let follows_digit = i > 0 && bytes[i - 1].is_ascii_digit();
+        let is_sign = bytes[i] == b'-'
+            && bytes.get(i + 1).is_some_and(u8::is_ascii_digit)
+            && !(ranges && follows_digit);
+
+        if is_sign || bytes[i].is_ascii_digit() {
+            let start = i;
+            i += 1;
+            while i < bytes.len() && bytes[i].is_ascii_digit() {
+                i += 1;
+            }
+            spans.push((start, i));
+        } else {
+            i += 1;
+        }
+    }
+
+    spans
+}
+
+// Every integer in `s`, e.g. `(0,-2) {3,5}` gives `[0, -2, 3, 5]`. Values
+// that do not fit in `T` are skipped.
+pub fn get_ints<T: FromStr>(s: &str) -> Vec<T> {
+    int_spans(s, false)
+        .into_iter()
+        .filter_map(|(start, end)| s[start..end].parse().ok())
+        .collect()
+}
+
+// Inclusive ranges written as `low-high`, e.g. `11-22,-5--3` gives
+// `[(11, 22), (-5, -3)]`. Integers that are not part of a range are skipped.
+pub fn get_int_ranges<T: FromStr>(s: &str) -> Vec<(T, T)> {
+    let spans = int_spans(s, true);
+    let mut ranges = vec![];
+    let mut idx = 0;
+
+    while idx + 1 < spans.len() {
+        let (low_start, low_end) = spans[idx];
+        let (high_start, high_end) = spans[idx + 1];
+
+        if &s[low_end..high_start] == "-"
+            && let (Ok(low), Ok(high)) = (
+                s[low_start..low_end].parse(),
+                s[high_start..high_end].parse(),
+            )
+        {
+            ranges.push((low, high));
+            idx += 2;
+        } else {
+            idx += 1;
+        }
+    }
+
+    ranges
+}
+
 pub fn get_lines(s: &str) -> Vec<String> {
     s.lines().map(|raw| raw.trim().to_string()).collect()
 }
 
+// Blocks of lines separated by one or more blank lines. Every line is
+// trimmed, as in `get_lines`.
+pub fn sections(s: &str) -> Vec<String> {
+    let mut sections = vec![];
+    let mut current: Vec<&str> = vec![];
+
+    for line in s.lines().map(str::trim) {
+        if line.is_empty() {
+            if !current.is_empty() {
+                sections.push(current.join("\n"));
+                current.clear();
+            }
+        } else {
+            current.push(line);
+        }
+    }
+
+    if !current.is_empty() {
+        sections.push(current.join("\n"));
+    }
+
+    sections
+}
+
+pub fn parse_lines<T: FromStr>(section: &str) -> Result<Vec<T>, String> {
+    parse_lines_with(section, |line| line.parse().ok())
+}
+
+// Fails with the first (1-based) line that `parse` rejects.
+pub fn parse_lines_with<T>(
+    section: &str,
+    parse: impl Fn(&str) -> Option<T>,
+) -> Result<Vec<T>, String> {
+    section
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            parse(line).ok_or_else(|| format!("Line {}: invalid value: {line}", idx + 1))
+        })
+        .collect()
+}
+
+// Consumes the sections of an input one typed parser at a time:
+//
+//     let mut sections = Sections::new(&input);
+//     let ranges = sections.next_with(|s| parse_lines_with(s, get_range))?;
+//     let ids: Vec<u64> = sections.next_with(parse_lines)?;
+pub struct Sections {
+    sections: Vec<String>,
+    next: usize,
+}
+
+impl Sections {
+    pub fn new(s: &str) -> Self {
+        Self {
+            sections: sections(s),
+            next: 0,
+        }
+    }
+
+    pub fn next_with<T>(
+        &mut self,
+        parse: impl FnOnce(&str) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let idx = self.next;
+        let section = self
+            .sections
+            .get(idx)
+            .ok_or_else(|| format!("Section {}: missing", idx + 1))?;
+        self.next += 1;
+
+        parse(section).map_err(|err| format!("Section {}: {err}", idx + 1))
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.sections.len() - self.next
+    }
+}
+
 pub fn split(s: &str, separator: &str) -> Vec<String> {
     s.split(separator).map(String::from).collect()
 }
 
-pub fn get_binary_grid(s: &str, one: char) -> Grid<u8> {
-    let lines = get_lines(s);
-    let rows = lines.len();
-    let raw_grid: Vec<u8> = lines.iter()
-        .map(|line| line.chars())
-        .flatten()
-        .map(|c| {
-            if c == one {
-                1
-            } else {
-                0
-            }
-        })
+// Grid lines with any stray `\r` removed and trailing blank lines, including
+// ones holding only whitespace, dropped.
+fn get_grid_lines(s: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = s
+        .lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
         .collect();
 
-    Grid::new(raw_grid, rows)
+    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+        lines.pop();
+    }
+
+    lines
 }
 
-pub fn get_raw_grid(s: &str) -> Grid<char> {
-    let lines: Vec<String> = s.lines()
-        .map(String::from)
-        .collect();
+pub fn get_binary_grid(s: &str, one: char) -> Result<Grid<u8>, String> {
+    let rows = get_grid_lines(s).into_iter().map(|line| {
+        line.trim()
+            .chars()
+            .map(|c| if c == one { 1 } else { 0 })
+            .collect()
+    });
 
-     let chars = lines
+    Grid::from_rows(rows)
+}
+
+pub fn get_raw_grid(s: &str) -> Result<Grid<char>, String> {
+    Grid::from_rows(get_grid_lines(s).into_iter().map(|line| line.chars().collect()))
+}
+
+// Short lines are padded with `pad` up to the length of the longest line.
+pub fn get_raw_grid_padded(s: &str, pad: char) -> Grid<char> {
+    let lines = get_grid_lines(s);
+    let cols = lines
         .iter()
-        .map(|line| line.chars())
-        .flatten()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    let chars = lines
+        .iter()
+        .flat_map(|line| line.chars().chain(iter::repeat(pad)).take(cols))
         .collect();
 
     Grid::new(chars, lines.len())
 }
+
+#[cfg(test)]
+mod test {
+    use std::env;
+    use std::fs;
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
+    use super::*;
+
+    const CONTENT: &str = "L68\nL30\nR48\n";
+
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path = env::temp_dir().join(format!("utils-{}-{name}", std::process::id()));
+        fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_read_plain_and_compressed() {
+        let mut gzip = GzEncoder::new(vec![], Compression::default());
+        gzip.write_all(CONTENT.as_bytes()).unwrap();
+        let gzip = gzip.finish().unwrap();
+        let zstd = zstd::encode_all(CONTENT.as_bytes(), 0).unwrap();
+
+        for (name, bytes) in [
+            ("plain.txt", CONTENT.as_bytes()),
+            ("day.txt.gz", &gzip),
+            ("day.txt.zst", &zstd),
+        ] {
+            let path = temp_file(name, bytes);
+            let content = read_file(&path);
+            let lines: Vec<String> = read_lines(&path).unwrap().map(Result::unwrap).collect();
+            fs::remove_file(&path).unwrap();
+
+            assert_eq!(CONTENT, content.unwrap(), "{name}");
+            assert_eq!(vec!["L68", "L30", "R48"], lines, "{name}");
+        }
+    }
+
+    #[test]
+    fn test_read_short_and_missing() {
+        let path = temp_file("short.txt", b"7");
+        assert_eq!("7", read_file(&path).unwrap());
+        fs::remove_file(&path).unwrap();
+
+        assert!(read_file(Path::new("/nonexistent/day1.txt")).is_err());
+    }
+
+    #[test]
+    fn test_get_ints() {
+        let line = "[.##.] (3) (1,3) {3,-5,4} x=-12, y=+7";
+
+        assert_eq!(vec![3, 1, 3, 3, -5, 4, -12, 7], get_ints::<i64>(line));
+        assert_eq!(vec![3, 1, 3, 3, 4, 7], get_ints::<u8>(line));
+        assert_eq!(vec![11, -22], get_ints::<i32>("11-22"));
+        assert_eq!(vec![1, 2], get_ints::<u8>("1 300 2"));
+        assert!(get_ints::<i64>("- -- abc").is_empty());
+    }
+
+    #[test]
+    fn test_get_int_ranges() {
+        let input = "11-22,95-115\n-5--3, 7, 1-x, 4-6\n";
+
+        assert_eq!(
+            vec![(11, 22), (95, 115), (-5, -3), (4, 6)],
+            get_int_ranges::<i64>(input)
+        );
+        assert_eq!(
+            vec![(11, 22), (95, 115), (4, 6)],
+            get_int_ranges::<u64>(input)
+        );
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "3-5\n  10-14\r\n\n\n 1\n5\n  \n";
+
+        assert_eq!(vec!["3-5\n10-14", "1\n5"], sections(input));
+        assert!(sections("\n\n").is_empty());
+    }
+
+    #[test]
+    fn test_typed_sections() {
+        let mut sections = Sections::new("3-5\n10-14\n\n1\n5\n\nx");
+
+        let ranges = sections.next_with(|s| {
+            parse_lines_with(s, |line| {
+                let (low, high) = line.split_once('-')?;
+                low.parse::<u64>().ok().zip(high.parse::<u64>().ok())
+            })
+        });
+        let ids: Result<Vec<u64>, _> = sections.next_with(parse_lines);
+        let bad: Result<Vec<u64>, _> = sections.next_with(parse_lines);
+        let missing: Result<Vec<u64>, _> = sections.next_with(parse_lines);
+
+        assert_eq!(Ok(vec![(3, 5), (10, 14)]), ranges);
+        assert_eq!(Ok(vec![1, 5]), ids);
+        assert_eq!(Err("Section 3: Line 1: invalid value: x".to_string()), bad);
+        assert_eq!(Err("Section 4: missing".to_string()), missing);
+        assert_eq!(0, sections.remaining());
+    }
+
+    #[test]
+    fn test_raw_grid_ragged() {
+        let result = get_raw_grid("..@\n.@\n@..");
+
+        assert_eq!(Err("Line 2: expected 3 columns, found 2".to_string()), result);
+    }
+
+    #[test]
+    fn test_raw_grid_crlf() {
+        let grid = get_raw_grid("..@\r\n.@.\r\n\r\n").unwrap();
+
+        assert_eq!(2, grid.rows());
+        assert_eq!(3, grid.cols());
+        assert_eq!(&['.', '@', '.'], grid.get_row(1));
+    }
+
+    #[test]
+    fn test_raw_grid_padded() {
+        let grid = get_raw_grid_padded("1 2\n3\n", ' ');
+
+        assert_eq!(3, grid.cols());
+        assert_eq!(&['3', ' ', ' '], grid.get_row(1));
+    }
+
+    #[test]
+    fn test_binary_grid() {
+        let grid = get_binary_grid(".@\n  @@\n", '@').unwrap();
+
+        assert_eq!(&[0, 1], grid.get_row(0));
+        assert_eq!(&[1, 1], grid.get_row(1));
+        assert!(get_binary_grid(".@\n@", '@').is_err());
+    }
+
+    #[test]
+    fn test_trailing_whitespace_line() {
+        let grid = get_binary_grid("..\n  \n", '@').unwrap();
+        assert_eq!((1, 2), (grid.rows(), grid.cols()));
+
+        let grid = get_raw_grid("..\n  \n").unwrap();
+        assert_eq!((1, 2), (grid.rows(), grid.cols()));
+    }
+}
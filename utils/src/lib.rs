@@ -1,22 +1,72 @@
 use clap::Parser;
 
+// Lets code generated by `AocParse` refer to `::utils` inside this crate too
+extern crate self as utils;
+
+pub mod bit_grid;
+pub mod compression;
+pub mod differential;
+pub mod dial;
+pub mod examples;
+pub mod generate;
 pub mod grid;
+pub mod grid_view;
 pub mod input_helpers;
+pub mod line_format;
 pub mod union_find;
 pub mod lin_alg;
+pub mod parallel;
+pub mod parse;
+pub mod point;
+pub mod prefix_sum;
+pub mod regions;
+pub mod report;
+pub mod sparse_grid;
+pub mod topology;
+
+pub use bit_grid::{BitGrid, NeighbourCounts};
+
+pub use compression::Compression;
+
+pub use dial::{Dial, DialStep, Direction, Rotation};
 
 pub use grid::Grid;
+pub use grid_view::GridView;
 
 pub use union_find::{UnionFind};
 
 pub use input_helpers::{
-    get_binary_grid, get_lines, get_raw_grid, get_uints, read_file, split, split_whitespace,
+    Sections, decompress, get_binary_grid, get_int_ranges, get_ints, get_lines, get_raw_grid,
+    get_raw_grid_padded, get_uints, open_input, parse_lines, parse_lines_with, read_file,
+    read_lines, sections, split, split_whitespace,
 };
 
+pub use aoc_derive::AocParse;
+
 pub use lin_alg::Matrix;
 
+pub use parallel::par_map_sum;
+
+pub use point::{Coordinate, Point2, Point3};
+
+pub use prefix_sum::PrefixSum2D;
+
+pub use regions::{Region, Regions};
+
+pub use report::{Format, Record, Report};
+
+pub use sparse_grid::SparseGrid;
+
+pub use topology::{Connectivity, Topology};
+
 #[derive(Parser, Debug)]
 pub struct Args {
+    /// Path to the puzzle input, or `-` for stdin. Gzip and zstd files are
+    /// decompressed automatically.
     #[arg(long = "puzzle_input")]
     pub puzzle_input: String,
+
+    /// How to print the answers.
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
 }
@@ -1,3 +1,4 @@
+#[derive(Debug)]
 pub struct Matrix {
     data: Vec<Vec<f64>>,
 }
@@ -12,32 +13,30 @@ impl Matrix {
     }
 
     pub fn cols(&self) -> usize {
-        self.data.get(0).map(|row| row.len()).unwrap_or(0)
+        self.data.first().map(|row| row.len()).unwrap_or(0)
     }
 
-    pub fn gauss_elim(&self, b: &Vec<f64>) -> Option<Vec<f64>> {
-        let N = self.rows();
-        let M = self.cols();
+    pub fn gauss_elim(&self, b: &[f64]) -> Option<Vec<f64>> {
+        let n = self.rows();
+        let m = self.cols();
 
-        if N != b.len() {
+        if n != b.len() {
             return None;
         }
 
         let mut data = self.data();
-        let mut bc = vec![0.0f64; M];
-        for i in 0..N {
-            bc[i] = b[i];
-        }
+        let mut bc = vec![0.0f64; m.max(n)];
+        bc[..n].copy_from_slice(b);
         let eps = 1e-9;
 
-        let mut pivot_col = vec![None; N];
+        let mut pivot_col = vec![None; n];
         let mut row = 0usize;
 
-        for col in 0..M {
+        for col in 0..m {
             let mut pivot = row;
-            let mut max_val = self.data[row][col].abs();
-            for r in (row + 1)..N {
-                let v = self.data[r][col].abs();
+            let mut max_val = data[row][col].abs();
+            for (r, data_row) in data.iter().enumerate().skip(row + 1) {
+                let v = data_row[col].abs();
                 if v > max_val {
                     max_val = v;
                     pivot = r;
@@ -52,12 +51,13 @@ impl Matrix {
             bc.swap(row, pivot);
 
             let pivot_val = data[row][col];
-            for c in col..M {
-                data[row][c] /= pivot_val;
+            for elem in &mut data[row][col..m] {
+                *elem /= pivot_val;
             }
             bc[row] /= pivot_val;
 
-            for r in 0..N {
+            let pivot_row = data[row].clone();
+            for r in 0..n {
                 if r == row {
                     continue;
                 }
@@ -67,8 +67,8 @@ impl Matrix {
                     continue;
                 }
 
-                for c in col..M {
-                    data[r][c] -= factor * data[row][c];
+                for (elem, pivot_elem) in data[r][col..m].iter_mut().zip(&pivot_row[col..m]) {
+                    *elem -= factor * pivot_elem;
                 }
                 bc[r] -= factor * bc[row];
             }
@@ -76,18 +76,16 @@ impl Matrix {
             pivot_col[row] = Some(col);
             row += 1;
 
-            if row == N {
+            if row == n {
                 break;
             }
         }
 
-        let mut res = vec![0.0f64; M];
-        for j in 0..M {
-            for i in 0..N {
-                if let Some(pc) = pivot_col[i]
-                    && pc == j
-                {
-                    res[j] = b[i];
+        let mut res = vec![0.0f64; m];
+        for (j, elem) in res.iter_mut().enumerate() {
+            for (i, pc) in pivot_col.iter().enumerate() {
+                if *pc == Some(j) {
+                    *elem = bc[i];
                     break;
                 }
             }
@@ -106,8 +104,8 @@ impl Matrix {
         for col in 0..m {
             let mut pivot = row;
             let mut max_val = data[row][col].abs();
-            for r in (row + 1)..n {
-                let v = data[r][col].abs();
+            for (r, data_row) in data.iter().enumerate().skip(row + 1) {
+                let v = data_row[col].abs();
                 if v > max_val {
                     max_val = v;
                     pivot = r;
@@ -121,22 +119,23 @@ impl Matrix {
             data.swap(row, pivot);
 
             let pivot_val = data[row][col];
-            for c in col..m {
-                data[row][c] /= pivot_val;
+            for elem in &mut data[row][col..m] {
+                *elem /= pivot_val;
             }
 
-            for r in 0..n {
+            let pivot_row = data[row].clone();
+            for (r, data_row) in data.iter_mut().enumerate() {
                 if r == row {
                     continue;
                 }
 
-                let factor = data[r][col];
+                let factor = data_row[col];
                 if factor.abs() < eps {
                     continue;
                 }
 
-                for c in col..m {
-                    data[r][c] -= factor * data[row][c];
+                for (elem, pivot_elem) in data_row[col..m].iter_mut().zip(&pivot_row[col..m]) {
+                    *elem -= factor * pivot_elem;
                 }
             }
 
@@ -150,22 +149,18 @@ impl Matrix {
         data
     }
 
-    pub fn vec_mult(&self, b: &Vec<f64>) -> Option<Vec<f64>> {
-      let n = self.rows();
+    pub fn vec_mult(&self, b: &[f64]) -> Option<Vec<f64>> {
       let m = self.cols();
 
       if m != b.len() {
         return None;
       }
 
-      let mut res = vec![0.0f64; n];
-
-      for i in 0..n {
-        res[i] = 0.0;
-        for j in 0..m {
-          res[i] += self.data[i][j] * b[j];
-        }
-      }
+      let res = self
+        .data
+        .iter()
+        .map(|row| row.iter().zip(b).map(|(a, b)| a * b).sum())
+        .collect();
 
       Some(res)
     }
@@ -177,7 +172,7 @@ impl Matrix {
         let mut rank = 0;
         for row in echelon {
             for col in row {
-                if col > eps {
+                if col.abs() > eps {
                     rank += 1;
                     break;
                 }
@@ -188,16 +183,54 @@ impl Matrix {
     }
 
     fn data(&self) -> Vec<Vec<f64>> {
-        let N = self.rows();
-        let M = self.cols();
-        let mut data = vec![vec![0.0f64; M]; N];
+        self.data.clone()
+    }
+}
 
-        for i in 0..N {
-            for j in 0..M {
-                data[i][j] = self.data[i][j];
-            }
+#[cfg(test)]
+mod test {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    fn assert_close(expected: &[f64], actual: &[f64]) {
+        assert_eq!(expected.len(), actual.len());
+        for (e, a) in expected.iter().zip(actual) {
+            assert!((e - a).abs() < 1e-6, "expected {expected:?}, found {actual:?}");
         }
+    }
 
-        data
+    #[test]
+    fn test_gauss_elim() {
+        // Needs a row swap and has a free variable
+        let matrix = Matrix::new(vec![vec![0.0, 2.0, 2.0], vec![1.0, 0.0, 1.0]]);
+
+        assert_close(&[3.0, 1.0, 0.0], &matrix.gauss_elim(&[2.0, 3.0]).unwrap());
+        assert_eq!(None, matrix.gauss_elim(&[1.0]));
+        assert_eq!(2, matrix.rank());
+        assert_eq!(1, Matrix::new(vec![vec![-1.0, 2.0], vec![2.0, -4.0]]).rank());
+    }
+
+    // Integer matrices of up to 4x4, and a solution to build a consistent
+    // right-hand side from
+    fn systems() -> impl Strategy<Value = (Matrix, Vec<f64>)> {
+        (1..=4usize, 1..=4usize).prop_flat_map(|(rows, cols)| {
+            let to_floats = |ints: Vec<i32>| ints.into_iter().map(f64::from).collect::<Vec<_>>();
+            let matrix = vec(vec(-4..=4i32, cols), rows)
+                .prop_map(move |data| Matrix::new(data.into_iter().map(to_floats).collect()));
+            (matrix, vec(-5..=5i32, cols).prop_map(to_floats))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solves_consistent_systems((matrix, x) in systems()) {
+            let b = matrix.vec_mult(&x).unwrap();
+            let solution = matrix.gauss_elim(&b).unwrap();
+
+            assert_close(&b, &matrix.vec_mult(&solution).unwrap());
+            prop_assert!(matrix.rank() <= matrix.rows().min(matrix.cols()));
+        }
     }
 }
@@ -0,0 +1,218 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+// Runtime support for `#[derive(AocParse)]`. The generated `FromStr` walks a
+// line with a `LineScanner`, matching the literal parts of the format and
+// handing each field's capture to one of the parsers below.
+pub struct LineScanner<'a> {
+    line: &'a str,
+    pos: usize,
+}
+
+impl<'a> LineScanner<'a> {
+    // Leading whitespace on the line is ignored.
+    pub fn new(line: &'a str) -> Self {
+        let mut scanner = Self { line, pos: 0 };
+        scanner.skip_whitespace();
+
+        scanner
+    }
+
+    // Whitespace in `literal` matches any run of whitespace, including none.
+    pub fn literal(&mut self, literal: &str) -> Result<(), String> {
+        for expected in literal.chars() {
+            if expected.is_whitespace() {
+                self.skip_whitespace();
+                continue;
+            }
+
+            match self.rest().chars().next() {
+                Some(c) if c == expected => self.pos += c.len_utf8(),
+                Some(c) => {
+                    return Err(format!(
+                        "Column {}: expected `{expected}`, found `{c}`",
+                        self.column()
+                    ));
+                }
+                None => {
+                    return Err(format!(
+                        "Column {}: expected `{expected}`, found end of line",
+                        self.column()
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Text up to the first `stop`, or up to the next whitespace if `stop` is
+    // empty. Surrounding whitespace is not part of the capture.
+    pub fn take_until(&mut self, field: &str, stop: &str) -> Result<&'a str, String> {
+        self.skip_whitespace();
+        let rest = self.rest();
+
+        let end = if stop.is_empty() {
+            rest.find(char::is_whitespace).unwrap_or(rest.len())
+        } else {
+            rest.find(stop).ok_or_else(|| {
+                format!(
+                    "Column {}: expected `{stop}` after field `{field}`",
+                    self.column()
+                )
+            })?
+        };
+        self.pos += end;
+
+        Ok(rest[..end].trim_end())
+    }
+
+    pub fn take_rest(&mut self) -> &'a str {
+        self.skip_whitespace();
+        let rest = self.rest();
+        self.pos = self.line.len();
+
+        rest.trim_end()
+    }
+
+    pub fn finish(&mut self) -> Result<(), String> {
+        self.skip_whitespace();
+        if self.rest().is_empty() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Column {}: unexpected trailing text `{}`",
+                self.column(),
+                self.rest()
+            ))
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.line[self.pos..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    fn column(&self) -> usize {
+        self.line[..self.pos].chars().count() + 1
+    }
+}
+
+pub fn parse_field<T>(field: &str, s: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    s.parse()
+        .map_err(|err| format!("Field `{field}`: invalid value `{s}`: {err}"))
+}
+
+// An empty capture is an empty list.
+pub fn parse_list<T>(field: &str, s: &str, separator: &str) -> Result<Vec<T>, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    if s.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    s.split(separator)
+        .map(|item| parse_field(field, item.trim()))
+        .collect()
+}
+
+// Groups such as `(1,3) (2)`, each wrapped in `open` and `close` and
+// separated by optional whitespace.
+pub fn parse_groups<T>(
+    field: &str,
+    s: &str,
+    open: char,
+    separator: &str,
+    close: char,
+) -> Result<Vec<Vec<T>>, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut groups = vec![];
+    let mut rest = s.trim_start();
+
+    while let Some(inner) = rest.strip_prefix(open) {
+        let end = inner
+            .find(close)
+            .ok_or_else(|| format!("Field `{field}`: unclosed `{open}`"))?;
+        groups.push(parse_list(field, &inner[..end], separator)?);
+        rest = inner[end + close.len_utf8()..].trim_start();
+    }
+
+    match rest.chars().next() {
+        Some(c) => Err(format!("Field `{field}`: expected `{open}`, found `{c}`")),
+        None => Ok(groups),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::AocParse;
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "[{lights}] {buttons:(,)*} {{{joltage:,}}}")]
+    struct Machine {
+        lights: String,
+        buttons: Vec<Vec<usize>>,
+        joltage: Vec<u64>,
+    }
+
+    #[derive(Debug, PartialEq, AocParse)]
+    #[aoc(format = "{name} -> {targets:, }")]
+    struct Node {
+        name: String,
+        targets: Vec<String>,
+    }
+
+    #[test]
+    fn test_derive() {
+        let machine: Machine = "  [.##.] (3) (1,3)  (2) {3,5,4,7}".parse().unwrap();
+
+        assert_eq!(
+            Machine {
+                lights: ".##.".to_string(),
+                buttons: vec![vec![3], vec![1, 3], vec![2]],
+                joltage: vec![3, 5, 4, 7],
+            },
+            machine
+        );
+
+        let node: Node = "a -> b, c".parse().unwrap();
+        assert_eq!(vec!["b", "c"], node.targets);
+        assert_eq!(Ok(vec![]), "a ->".parse::<Node>().map(|n| n.targets));
+    }
+
+    #[test]
+    fn test_derive_errors() {
+        let parse = |s: &str| s.parse::<Machine>().unwrap_err();
+
+        assert_eq!("Column 1: expected `[`, found `.`", parse(".##.] (3) {3}"));
+        assert_eq!(
+            "Column 2: expected `]` after field `lights`",
+            parse("[.##. (3) {3}")
+        );
+        assert_eq!(
+            "Field `buttons`: invalid value `x`: invalid digit found in string",
+            parse("[.#] (1,x) {3}")
+        );
+        assert_eq!(
+            "Field `buttons`: expected `(`, found `3`",
+            parse("[.#] (1) 3 {3}")
+        );
+        assert_eq!(
+            "Column 16: unexpected trailing text `!`",
+            parse("[.#] (1) {3,4} !")
+        );
+    }
+}
@@ -0,0 +1,41 @@
+use std::iter::Sum;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+// Maps every item and sums the results. With the `parallel` feature the items
+// are spread over rayon's thread pool, otherwise they are mapped in order.
+// The bounds are the same either way, so callers build with or without it.
+pub fn par_map_sum<T, S>(items: &[T], f: impl Fn(&T) -> S + Sync + Send) -> S
+where
+    T: Sync,
+    S: Send + Sum<S>,
+{
+    #[cfg(feature = "parallel")]
+    {
+        items.par_iter().map(f).sum()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        items.iter().map(f).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_par_map_sum() {
+        let lines: Vec<String> = (1..=1000).map(|n| n.to_string()).collect();
+
+        let sum: u64 = par_map_sum(&lines, |line| line.parse::<u64>().unwrap());
+        assert_eq!(500500, sum);
+
+        let empty: [u8; 0] = [];
+        assert_eq!(0, par_map_sum(&empty, |&n| n as usize));
+        assert_eq!(Some(6), par_map_sum(&[1, 2, 3], |&n| Some(n)),);
+        assert_eq!(None, par_map_sum(&[1, 2, 3], |&n| (n != 2).then_some(n)));
+    }
+}
@@ -0,0 +1,371 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::rc::Rc;
+use std::str::FromStr;
+
+// Small parser combinators over `&str`. A parser is any
+// `Fn(Input) -> ParseResult<T>`, and the functions below build bigger
+// parsers from smaller ones:
+//
+//     let direction = alt((map(tag("L"), |_| Left), map(tag("R"), |_| Right)));
+//     let rotation = pair(direction, number::<u64>());
+//     let rotations = parse_all(separated_list(rotation, tag("\n")), &input)?;
+
+// Besides the position, an input remembers the furthest error that a
+// repetition gave up on, so a later failure can point there instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input<'a> {
+    text: &'a str,
+    pos: usize,
+    failure: Option<Rc<ParseError>>,
+}
+
+impl<'a> Input<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            pos: 0,
+            failure: None,
+        }
+    }
+
+    pub fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    // Byte offset from the start of the text.
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos == self.text.len()
+    }
+
+    // A remembered failure is dropped once parsing has moved past it.
+    fn advance(self, len: usize) -> Self {
+        let pos = self.pos + len;
+        let failure = self.failure.filter(|failure| failure.pos >= pos);
+
+        Self { pos, failure, ..self }
+    }
+
+    fn with_failure(self, err: ParseError) -> Self {
+        let err = match self.failure {
+            Some(failure) => furthest(ParseError::clone(&failure), err),
+            None => err,
+        };
+
+        Self {
+            failure: Some(Rc::new(err)),
+            ..self
+        }
+    }
+
+    // The error at this position, or the remembered failure if it got further.
+    pub fn error(&self, expected: impl Into<String>) -> ParseError {
+        let before = &self.text[..self.pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before.rsplit('\n').next().unwrap_or("").chars().count() + 1;
+
+        let err = ParseError {
+            pos: self.pos,
+            line,
+            column,
+            expected: expected.into(),
+        };
+        match &self.failure {
+            Some(failure) => furthest(ParseError::clone(failure), err),
+            None => err,
+        }
+    }
+}
+
+// `line` and `column` are 1-based, `pos` is the byte offset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub pos: usize,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: expected {}",
+            self.line, self.column, self.expected
+        )
+    }
+}
+
+impl From<ParseError> for String {
+    fn from(err: ParseError) -> Self {
+        err.to_string()
+    }
+}
+
+pub type ParseResult<'a, T> = Result<(T, Input<'a>), ParseError>;
+
+pub trait Parser<'a, T> {
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T>;
+}
+
+impl<'a, T, F> Parser<'a, T> for F
+where
+    F: Fn(Input<'a>) -> ParseResult<'a, T>,
+{
+    fn parse(&self, input: Input<'a>) -> ParseResult<'a, T> {
+        self(input)
+    }
+}
+
+// Runs `parser` on the whole of `s`, failing if anything is left over.
+pub fn parse_all<'a, T>(parser: impl Parser<'a, T>, s: &'a str) -> Result<T, ParseError> {
+    let (value, rest) = parser.parse(Input::new(s))?;
+    if !rest.is_empty() {
+        return Err(rest.error("end of input"));
+    }
+
+    Ok(value)
+}
+
+pub fn tag<'a, 'p>(expected: &'p str) -> impl Parser<'a, &'a str> + 'p {
+    move |input: Input<'a>| {
+        if input.rest().starts_with(expected) {
+            let matched = &input.rest()[..expected.len()];
+            Ok((matched, input.advance(expected.len())))
+        } else {
+            Err(input.error(format!("`{}`", expected.escape_debug())))
+        }
+    }
+}
+
+// Optional `-` followed by digits, parsed as `T`.
+pub fn number<'a, T: FromStr>() -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len() - sign);
+
+        if digits == 0 {
+            return Err(input.error("a number"));
+        }
+
+        let len = sign + digits;
+        match rest[..len].parse() {
+            Ok(value) => Ok((value, input.advance(len))),
+            Err(_) => Err(input.error(format!("a number in range, found `{}`", &rest[..len]))),
+        }
+    }
+}
+
+// Zero or more whitespace characters.
+pub fn spaces<'a>() -> impl Parser<'a, &'a str> {
+    move |input: Input<'a>| {
+        let rest = input.rest();
+        let len = rest.len() - rest.trim_start().len();
+        Ok((&rest[..len], input.advance(len)))
+    }
+}
+
+pub fn map<'a, T, U>(parser: impl Parser<'a, T>, f: impl Fn(T) -> U) -> impl Parser<'a, U> {
+    move |input: Input<'a>| parser.parse(input).map(|(value, rest)| (f(value), rest))
+}
+
+pub fn pair<'a, A, B>(
+    first: impl Parser<'a, A>,
+    second: impl Parser<'a, B>,
+) -> impl Parser<'a, (A, B)> {
+    move |input: Input<'a>| {
+        let (a, input) = first.parse(input)?;
+        let (b, input) = second.parse(input)?;
+        Ok(((a, b), input))
+    }
+}
+
+// `parser` between `open` and `close`, keeping only its value.
+pub fn delimited<'a, O, T, C>(
+    open: impl Parser<'a, O>,
+    parser: impl Parser<'a, T>,
+    close: impl Parser<'a, C>,
+) -> impl Parser<'a, T> {
+    move |input: Input<'a>| {
+        let (_, input) = open.parse(input)?;
+        let (value, input) = parser.parse(input)?;
+        let (_, input) = close.parse(input)?;
+        Ok((value, input))
+    }
+}
+
+// Zero or more `item`s separated by `separator`. A trailing separator is
+// left unconsumed. The error that ended the list is remembered on the input.
+pub fn separated_list<'a, T, S>(
+    item: impl Parser<'a, T>,
+    separator: impl Parser<'a, S>,
+) -> impl Parser<'a, Vec<T>> {
+    move |input: Input<'a>| {
+        let mut items = vec![];
+        let mut input = match item.parse(input.clone()) {
+            Ok((first, input)) => {
+                items.push(first);
+                input
+            }
+            Err(err) => return Ok((items, input.with_failure(err))),
+        };
+
+        loop {
+            let next = separator
+                .parse(input.clone())
+                .and_then(|(_, after_separator)| item.parse(after_separator));
+            match next {
+                Ok((value, after_item)) => {
+                    items.push(value);
+                    input = after_item;
+                }
+                Err(err) => return Ok((items, input.with_failure(err))),
+            }
+        }
+    }
+}
+
+// Zero or more repetitions of `parser`, stopping early if it matches
+// without consuming anything. The error that ended the repetition is
+// remembered on the input.
+pub fn many<'a, T>(parser: impl Parser<'a, T>) -> impl Parser<'a, Vec<T>> {
+    move |mut input: Input<'a>| {
+        let mut items = vec![];
+        loop {
+            match parser.parse(input.clone()) {
+                Ok((value, next)) if next.pos() != input.pos() => {
+                    items.push(value);
+                    input = next;
+                }
+                Ok(_) => return Ok((items, input)),
+                Err(err) => return Ok((items, input.with_failure(err))),
+            }
+        }
+    }
+}
+
+// A tuple of parsers tried in order by `alt`.
+pub trait Alt<'a, T> {
+    fn choice(&self, input: Input<'a>) -> ParseResult<'a, T>;
+}
+
+// Keeps the error that got furthest, merging what was expected on a tie.
+fn furthest(a: ParseError, b: ParseError) -> ParseError {
+    match a.pos.cmp(&b.pos) {
+        Ordering::Less => b,
+        Ordering::Greater => a,
+        Ordering::Equal if a.expected == b.expected => a,
+        Ordering::Equal => ParseError {
+            expected: format!("{} or {}", a.expected, b.expected),
+            ..a
+        },
+    }
+}
+
+macro_rules! impl_alt {
+    ($first:ident $(, $parser:ident $idx:tt)*) => {
+        impl<'a, T, $first: Parser<'a, T> $(, $parser: Parser<'a, T>)*> Alt<'a, T>
+            for ($first, $($parser,)*)
+        {
+            fn choice(&self, input: Input<'a>) -> ParseResult<'a, T> {
+                let mut error = match self.0.parse(input.clone()) {
+                    Ok(result) => return Ok(result),
+                    Err(err) => err,
+                };
+                $(
+                    match self.$idx.parse(input.clone()) {
+                        Ok(result) => return Ok(result),
+                        Err(err) => error = furthest(error, err),
+                    }
+                )*
+
+                Err(error)
+            }
+        }
+    };
+}
+
+impl_alt!(A, B 1);
+impl_alt!(A, B 1, C 2);
+impl_alt!(A, B 1, C 2, D 3);
+impl_alt!(A, B 1, C 2, D 3, E 4);
+impl_alt!(A, B 1, C 2, D 3, E 4, F 5);
+
+// The first of `parsers` that matches.
+pub fn alt<'a, T>(parsers: impl Alt<'a, T>) -> impl Parser<'a, T> {
+    move |input: Input<'a>| parsers.choice(input)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_number_and_tag() {
+        assert_eq!(Ok(-42), parse_all(number::<i64>(), "-42"));
+        assert_eq!(
+            Ok(("x", 7)),
+            parse_all(pair(tag("x"), number::<u8>()), "x7")
+        );
+
+        let err = parse_all(number::<u8>(), "300").unwrap_err();
+        assert_eq!("a number in range, found `300`", err.expected);
+
+        let err = parse_all(number::<i32>(), "12a").unwrap_err();
+        assert_eq!("Line 1, column 3: expected end of input", err.to_string());
+    }
+
+    #[test]
+    fn test_alt() {
+        let direction = || alt((map(tag("L"), |_| -1), map(tag("R"), |_| 1)));
+        let rotation = || map(pair(direction(), number::<i64>()), |(d, n)| d * n);
+
+        assert_eq!(Ok(-68), parse_all(rotation(), "L68"));
+        assert_eq!(Ok(48), parse_all(rotation(), "R48"));
+        assert_eq!(
+            "Line 1, column 1: expected `L` or `R`",
+            parse_all(rotation(), "X1").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_lists() {
+        let group = delimited(
+            tag("("),
+            separated_list(number::<usize>(), tag(",")),
+            tag(")"),
+        );
+        let groups = separated_list(group, tag(" "));
+
+        assert_eq!(
+            Ok(vec![vec![3], vec![1, 3], vec![]]),
+            parse_all(groups, "(3) (1,3) ()")
+        );
+        assert_eq!(Ok(vec!["ab", "ab"]), parse_all(many(tag("ab")), "abab"));
+        assert_eq!(Ok(vec![]), parse_all(many(spaces()), ""));
+    }
+
+    #[test]
+    fn test_error_position() {
+        let line = || pair(number::<u32>(), tag("\n"));
+        let err = parse_all(many(line()), "1\n2\n3x\n").unwrap_err();
+
+        assert_eq!((3, 2), (err.line, err.column));
+        assert_eq!(5, err.pos);
+        assert_eq!("`\\n`", err.expected);
+
+        let err = parse_all(many(line()), "1\n2\nx\n").unwrap_err();
+        assert_eq!("Line 3, column 1: expected a number or end of input", err.to_string());
+
+        let list = separated_list(number::<u32>(), tag(","));
+        let err = parse_all(list, "1,2,x").unwrap_err();
+        assert_eq!("Line 1, column 5: expected a number", err.to_string());
+    }
+}
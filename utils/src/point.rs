@@ -0,0 +1,230 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use crate::parse::{delimited, number, parse_all, separated_list, spaces, tag};
+
+pub trait Coordinate:
+    Copy
+    + Ord
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    fn abs(self) -> Self;
+}
+
+macro_rules! impl_coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_coordinate!(i8, i16, i32, i64, i128, isize);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    pub fn euclidean_sq(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.x * d.x + d.y * d.y
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x)
+            .abs()
+            .max((self.y - other.y).abs())
+            .max((self.z - other.z).abs())
+    }
+
+    pub fn euclidean_sq(&self, other: &Self) -> T {
+        let d = *self - *other;
+        d.x * d.x + d.y * d.y + d.z * d.z
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),* }) => {
+        impl<T: Add<Output = T>> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self {
+                Self { $($field: self.$field + rhs.$field),* }
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self {
+                Self { $($field: self.$field - rhs.$field),* }
+            }
+        }
+
+        impl<T: Copy + Mul<Output = T>> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, rhs: T) -> Self {
+                Self { $($field: self.$field * rhs),* }
+            }
+        }
+
+        impl<T: Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self { $($field: -self.$field),* }
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $point<T> {
+            fn add_assign(&mut self, rhs: Self) {
+                $(self.$field += rhs.$field;)*
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $point<T> {
+            fn sub_assign(&mut self, rhs: Self) {
+                $(self.$field -= rhs.$field;)*
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+// Points sit on a single line, so errors only name the column and callers
+// can add the line themselves.
+fn parse_coordinates<T: FromStr, const N: usize>(s: &str) -> Result<[T; N], String> {
+    let coordinate = delimited(spaces(), number(), spaces());
+    let coords: Vec<T> = parse_all(separated_list(coordinate, tag(",")), s)
+        .map_err(|err| format!("column {}: expected {}", err.column, err.expected))?;
+    let found = coords.len();
+
+    coords
+        .try_into()
+        .map_err(|_| format!("expected {N} coordinates, got {found}: {s}"))
+}
+
+impl<T: FromStr> FromStr for Point2<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coordinates(s)?;
+        Ok(Self { x, y })
+    }
+}
+
+impl<T: FromStr> FromStr for Point3<T> {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s)?;
+        Ok(Self { x, y, z })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Ok(Point2::new(7, -1)), "7,-1".parse::<Point2<i64>>());
+        assert_eq!(Ok(Point3::new(-162, 817, 0)), " -162, 817,0".parse::<Point3<i64>>());
+        assert!("1,2,3".parse::<Point2<i64>>().is_err());
+        assert!("1,x".parse::<Point2<i64>>().is_err());
+        assert!("1,2".parse::<Point3<i64>>().is_err());
+        assert_eq!(
+            Err("column 3: expected a number".to_string()),
+            "1,x".parse::<Point2<i64>>()
+        );
+    }
+
+    #[test]
+    fn test_distances() {
+        let a = Point2::new(-2i64, 3);
+        let b = Point2::new(4i64, -5);
+
+        assert_eq!(14, a.manhattan(&b));
+        assert_eq!(8, a.chebyshev(&b));
+        assert_eq!(100, a.euclidean_sq(&b));
+
+        let a = Point3::new(1i64, -1, 2);
+        let b = Point3::new(-1i64, 2, 8);
+
+        assert_eq!(11, a.manhattan(&b));
+        assert_eq!(6, a.chebyshev(&b));
+        assert_eq!(49, a.euclidean_sq(&b));
+    }
+
+    #[test]
+    fn test_ops() {
+        let mut p = Point2::new(1i64, 2) + Point2::new(-3, 4);
+        assert_eq!(Point2::new(-2, 6), p);
+
+        p -= Point2::new(1, 1);
+        assert_eq!(Point2::new(-3, 5), p);
+        assert_eq!(Point2::new(3, -5), -p);
+        assert_eq!(Point2::new(-6, 10), p * 2);
+        assert_eq!(Point3::new(0, 0, 0), Point3::new(1, 2, 3) - Point3::new(1, 2, 3));
+    }
+}
@@ -0,0 +1,69 @@
+use crate::grid::Grid;
+
+#[derive(Debug)]
+pub struct PrefixSum2D {
+    rows: usize,
+    cols: usize,
+    sums: Vec<u64>,
+}
+
+impl PrefixSum2D {
+    pub fn new<T>(grid: &Grid<T>) -> Self
+    where
+        T: Copy + Into<u64>,
+    {
+        let rows = grid.rows();
+        let cols = grid.cols();
+        let width = cols + 1;
+        let mut sums = vec![0u64; (rows + 1) * width];
+
+        for i in 0..rows {
+            for j in 0..cols {
+                let elem: u64 = grid.get(i, j).map(Into::into).unwrap_or(0);
+                sums[(i + 1) * width + j + 1] =
+                    elem + sums[i * width + j + 1] + sums[(i + 1) * width + j] - sums[i * width + j];
+            }
+        }
+
+        Self { rows, cols, sums }
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    // Sum over the inclusive ranges `rows.0..=rows.1` and `cols.0..=cols.1`.
+    pub fn sum(&self, rows: (usize, usize), cols: (usize, usize)) -> Option<u64> {
+        if rows.0 > rows.1 || cols.0 > cols.1 || rows.1 >= self.rows || cols.1 >= self.cols {
+            return None;
+        }
+
+        let width = self.cols + 1;
+        let at = |i: usize, j: usize| self.sums[i * width + j];
+
+        Some(at(rows.1 + 1, cols.1 + 1) + at(rows.0, cols.0)
+            - at(rows.0, cols.1 + 1)
+            - at(rows.1 + 1, cols.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sum() {
+        let grid = Grid::new(vec![1u8, 2, 3, 4, 5, 6], 2);
+        let prefix = PrefixSum2D::new(&grid);
+
+        assert_eq!(Some(21), prefix.sum((0, 1), (0, 2)));
+        assert_eq!(Some(7), prefix.sum((0, 1), (1, 1)));
+        assert_eq!(Some(6), prefix.sum((1, 1), (2, 2)));
+        assert_eq!(Some(16), prefix.sum((0, 1), (1, 2)));
+        assert_eq!(None, prefix.sum((0, 2), (0, 0)));
+    }
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use crate::grid::Grid;
+use crate::topology::{Connectivity, Topology};
+use crate::union_find::UnionFind;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    pub label: usize,
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+    // Inclusive corners: (min_row, min_col) and (max_row, max_col)
+    pub bounding_box: ((usize, usize), (usize, usize)),
+}
+
+#[derive(Debug)]
+pub struct Regions {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+const DIAGONAL: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+impl<T> Grid<T> {
+    // Labels are numbered in row-major order of each region's first cell.
+    pub fn label_regions(
+        &self,
+        connectivity: Connectivity,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        self.label_regions_with_topology(connectivity, Topology::Bounded, same_region)
+    }
+
+    // On a wrapping grid regions continue across the edges, and cells that
+    // meet across an edge are not part of the perimeter.
+    pub fn label_regions_with_topology(
+        &self,
+        connectivity: Connectivity,
+        topology: Topology,
+        same_region: impl Fn(&T, &T) -> bool,
+    ) -> Regions {
+        let rows = self.rows();
+        let cols = self.cols();
+        let mut uf = UnionFind::new(rows * cols);
+
+        // Only look forward so every adjacent pair is checked once
+        let mut forward = vec![(0, 1), (1, 0)];
+        if connectivity == Connectivity::Eight {
+            forward.extend([(1, -1), (1, 1)]);
+        }
+
+        for ((i, j), elem) in self.enumerate_row_major() {
+            for &(dr, dc) in &forward {
+                if let Some((r, c)) = self.step((i, j), (dr, dc), topology)
+                    && same_region(elem, &self[(r, c)])
+                {
+                    uf.union(i * cols + j, r * cols + c);
+                }
+            }
+        }
+
+        let mut root_labels = HashMap::new();
+        let mut labels = Vec::with_capacity(rows * cols);
+        for idx in 0..rows * cols {
+            let next = root_labels.len();
+            labels.push(*root_labels.entry(uf.find(idx)).or_insert(next));
+        }
+        let labels = Grid::new(labels, rows);
+
+        let mut regions: Vec<Region> = (0..root_labels.len())
+            .map(|label| Region {
+                label,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounding_box: ((usize::MAX, usize::MAX), (0, 0)),
+            })
+            .collect();
+
+        for ((i, j), &label) in labels.enumerate_row_major() {
+            let same = |(dr, dc): (isize, isize)| {
+                labels
+                    .step((i, j), (dr, dc), topology)
+                    .is_some_and(|pos| labels[pos] == label)
+            };
+
+            let region = &mut regions[label];
+            region.area += 1;
+            region.perimeter += Connectivity::Four
+                .offsets()
+                .iter()
+                .filter(|&&dir| !same(dir))
+                .count();
+
+            // Every side of a rectilinear region starts at exactly one corner
+            region.sides += DIAGONAL
+                .iter()
+                .filter(|&&(dr, dc)| {
+                    let vertical = same((dr, 0));
+                    let horizontal = same((0, dc));
+                    (!vertical && !horizontal) || (vertical && horizontal && !same((dr, dc)))
+                })
+                .count();
+
+            let ((min_row, min_col), (max_row, max_col)) = &mut region.bounding_box;
+            *min_row = (*min_row).min(i);
+            *min_col = (*min_col).min(j);
+            *max_row = (*max_row).max(i);
+            *max_col = (*max_col).max(j);
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input_helpers::get_raw_grid;
+
+    #[test]
+    fn test_four_connected() {
+        let grid = get_raw_grid("AAAA\nBBCD\nBBCC\nEEEC").unwrap();
+        let Regions { labels, regions } = grid.label_regions(Connectivity::Four, |a, b| a == b);
+
+        let summary: Vec<_> = regions
+            .iter()
+            .map(|r| (grid[r.bounding_box.0], r.area, r.perimeter, r.sides))
+            .collect();
+
+        assert_eq!(
+            vec![
+                ('A', 4, 10, 4),
+                ('B', 4, 8, 4),
+                ('C', 4, 10, 8),
+                ('D', 1, 4, 4),
+                ('E', 3, 8, 4),
+            ],
+            summary
+        );
+        assert_eq!(((1, 2), (3, 3)), regions[2].bounding_box);
+        assert_eq!(labels[(2, 3)], labels[(1, 2)]);
+    }
+
+    #[test]
+    fn test_eight_connected() {
+        let grid = get_raw_grid("#..\n.#.\n..#").unwrap();
+
+        let four = grid.label_regions(Connectivity::Four, |a, b| a == b);
+        let eight = grid.label_regions(Connectivity::Eight, |a, b| a == b);
+
+        assert_eq!(5, four.regions.len());
+        assert_eq!(2, eight.regions.len());
+        assert_eq!(3, eight.regions[0].area);
+        assert_eq!(((0, 0), (2, 2)), eight.regions[0].bounding_box);
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let grid = get_raw_grid("#..#\n....\n#..#").unwrap();
+
+        let bounded = grid.label_regions(Connectivity::Four, |a, b| a == b);
+        let wrapping =
+            grid.label_regions_with_topology(Connectivity::Four, Topology::Wrapping, |a, b| a == b);
+
+        assert_eq!(5, bounded.regions.len());
+        assert_eq!(2, wrapping.regions.len());
+        assert_eq!(4, wrapping.regions[0].area);
+        assert_eq!(8, wrapping.regions[0].perimeter);
+        assert_eq!(4, wrapping.regions[0].sides);
+    }
+}
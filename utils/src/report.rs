@@ -0,0 +1,148 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+// A single answer. Answers are kept as strings since days return different
+// integer types, some of which don't fit in a JSON number exactly.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub day: u32,
+    pub part: u32,
+    pub answer: String,
+    pub elapsed_ns: u64,
+    pub input: String,
+}
+
+impl Record {
+    pub fn to_text(&self) -> String {
+        let part = match self.part {
+            1 => "One",
+            2 => "Two",
+            _ => return format!("Part {}: {}", self.part, self.answer),
+        };
+
+        format!("Part {part}: {}", self.answer)
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Records always serialize")
+    }
+}
+
+// All records of a batch as one JSON array
+pub fn records_to_json(records: &[Record]) -> String {
+    serde_json::to_string_pretty(records).expect("Records always serialize")
+}
+
+pub fn records_from_json(s: &str) -> Result<Vec<Record>, String> {
+    serde_json::from_str(s).map_err(|err| format!("Invalid records: {err}"))
+}
+
+// Times each part of one day and prints its answer as soon as it is known,
+// either as `Part One: N` or as one JSON record per line.
+pub struct Report {
+    day: u32,
+    input: String,
+    format: Format,
+    records: Vec<Record>,
+}
+
+impl Report {
+    pub fn new(day: u32, input: &str, format: Format) -> Self {
+        Self {
+            day,
+            input: input.to_string(),
+            format,
+            records: vec![],
+        }
+    }
+
+    pub fn from_args(day: u32, args: &crate::Args) -> Self {
+        Self::new(day, &args.puzzle_input, args.format)
+    }
+
+    pub fn part<T: Display>(&mut self, part: u32, solve: impl FnOnce() -> T) {
+        let start = Instant::now();
+        let answer = solve();
+        self.record(part, answer, start.elapsed());
+    }
+
+    // Like `part`, but a part that fails is passed on instead of recorded.
+    pub fn try_part<T: Display>(
+        &mut self,
+        part: u32,
+        solve: impl FnOnce() -> Result<T, String>,
+    ) -> Result<(), String> {
+        let start = Instant::now();
+        let answer = solve().map_err(|err| format!("Part {part}: {err}"))?;
+        self.record(part, answer, start.elapsed());
+        Ok(())
+    }
+
+    fn record(&mut self, part: u32, answer: impl Display, elapsed: Duration) {
+        let record = Record {
+            day: self.day,
+            part,
+            answer: answer.to_string(),
+            elapsed_ns: elapsed.as_nanos() as u64,
+            input: self.input.clone(),
+        };
+        match self.format {
+            Format::Text => println!("{}", record.to_text()),
+            Format::Json => println!("{}", record.to_json()),
+        }
+        self.records.push(record);
+    }
+
+    pub fn records(&self) -> &[Record] {
+        &self.records
+    }
+
+    pub fn into_records(self) -> Vec<Record> {
+        self.records
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let mut report = Report::new(3, "inputs/day3.txt", Format::Json);
+        report.part(1, || 17100u64);
+        report.part(2, || -4i64);
+
+        let records = report.records();
+        assert_eq!("Part One: 17100", records[0].to_text());
+        assert_eq!("Part Two: -4", records[1].to_text());
+
+        let json = records[0].to_json();
+        assert!(json.starts_with(r#"{"day":3,"part":1,"answer":"17100","elapsed_ns":"#));
+        assert!(json.ends_with(r#","input":"inputs/day3.txt"}"#));
+
+        assert_eq!(Ok(records.to_vec()), records_from_json(&records_to_json(records)));
+        assert!(records_from_json("[{\"day\": 1}]").is_err());
+    }
+
+    #[test]
+    fn test_failed_part() {
+        let mut report = Report::new(5, "inputs/day5.txt", Format::Json);
+
+        assert_eq!(Ok(()), report.try_part(1, || Ok::<_, String>(598)));
+        assert_eq!(
+            Err("Part 2: too big".to_string()),
+            report.try_part(2, || Err::<u64, _>("too big".to_string()))
+        );
+        assert_eq!(1, report.records().len());
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::Index;
+
+use crate::grid::Grid;
+
+type Pos = (i64, i64);
+
+// Unbounded grid over signed `(row, col)` positions. Only occupied cells are
+// stored, and the bounding box grows with them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Pos, T>,
+    bounds: Option<(Pos, Pos)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        Self {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn insert(&mut self, pos: Pos, elem: T) -> Option<T> {
+        self.bounds = Some(match self.bounds {
+            Some(((min_row, min_col), (max_row, max_col))) => (
+                (min_row.min(pos.0), min_col.min(pos.1)),
+                (max_row.max(pos.0), max_col.max(pos.1)),
+            ),
+            None => (pos, pos),
+        });
+
+        self.cells.insert(pos, elem)
+    }
+
+    pub fn remove(&mut self, pos: Pos) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+
+        // Only a cell on the edge of the box can shrink it
+        if let Some(((min_row, min_col), (max_row, max_col))) = self.bounds
+            && (pos.0 == min_row || pos.0 == max_row || pos.1 == min_col || pos.1 == max_col)
+        {
+            self.bounds = self
+                .cells
+                .keys()
+                .fold(None, |bounds, &(r, c)| match bounds {
+                    Some(((min_r, min_c), (max_r, max_c))) => {
+                        Some(((r.min(min_r), c.min(min_c)), (r.max(max_r), c.max(max_c))))
+                    }
+                    None => Some(((r, c), (r, c))),
+                });
+        }
+
+        Some(removed)
+    }
+
+    // Inclusive corners (min_row, min_col) and (max_row, max_col), if any
+    // cell is occupied.
+    pub fn bounds(&self) -> Option<(Pos, Pos)> {
+        self.bounds
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> + '_ {
+        self.cells.iter().map(|(&pos, elem)| (pos, elem))
+    }
+
+    // Cells of `grid` for which `keep` holds, at their grid positions.
+    pub fn from_grid(grid: &Grid<T>, keep: impl Fn(&T) -> bool) -> Self
+    where
+        T: Clone,
+    {
+        let mut sparse = Self::new();
+        for ((i, j), elem) in grid.enumerate_row_major() {
+            if keep(elem) {
+                sparse.insert((i as i64, j as i64), elem.clone());
+            }
+        }
+
+        sparse
+    }
+
+    // Dense copy of the bounding box with empty cells set to `background`,
+    // together with the position of its top-left cell.
+    pub fn to_grid(&self, background: T) -> (Grid<T>, Pos)
+    where
+        T: Clone,
+    {
+        let Some(((min_row, min_col), (max_row, max_col))) = self.bounds else {
+            return (Grid::fill(0, 0, background), (0, 0));
+        };
+
+        let rows = (max_row - min_row + 1) as usize;
+        let cols = (max_col - min_col + 1) as usize;
+        let mut grid = Grid::fill(rows, cols, background);
+        for (&(r, c), elem) in &self.cells {
+            grid[((r - min_row) as usize, (c - min_col) as usize)] = elem.clone();
+        }
+
+        (grid, (min_row, min_col))
+    }
+
+    pub fn render(&self, to_char: impl Fn(&T) -> char, background: char) -> String {
+        let Some(((min_row, min_col), (max_row, max_col))) = self.bounds else {
+            return String::new();
+        };
+
+        let mut out = String::new();
+        for r in min_row..=max_row {
+            for c in min_col..=max_col {
+                out.push(self.get((r, c)).map(&to_char).unwrap_or(background));
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+}
+
+impl<T> Index<Pos> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        match self.get(pos) {
+            Some(elem) => elem,
+            None => panic!("No cell at {pos:?} in sparse grid"),
+        }
+    }
+}
+
+impl fmt::Display for SparseGrid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render(|&c| c, '.'))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        let mut grid = SparseGrid::new();
+        assert_eq!(None, grid.bounds());
+
+        grid.insert((0, 0), 'S');
+        grid.insert((-2, 3), '#');
+        grid.insert((1, -1), '#');
+        assert_eq!(Some(((-2, -1), (1, 3))), grid.bounds());
+
+        assert_eq!(Some('#'), grid.remove((-2, 3)));
+        assert_eq!(Some(((0, -1), (1, 0))), grid.bounds());
+        assert_eq!(None, grid.remove((5, 5)));
+        assert_eq!('S', grid[(0, 0)]);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut grid = SparseGrid::new();
+        grid.insert((-1, -1), '#');
+        grid.insert((0, 1), 'S');
+
+        assert_eq!("#..\n..S\n", grid.to_string());
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense = Grid::new(vec!['.', '#', '.', '.', '.', '#'], 2);
+        let sparse = SparseGrid::from_grid(&dense, |&c| c == '#');
+
+        assert_eq!(2, sparse.len());
+        assert_eq!(Some(((0, 1), (1, 2))), sparse.bounds());
+
+        let (grid, origin) = sparse.to_grid('.');
+        assert_eq!((0, 1), origin);
+        assert_eq!(Grid::new(vec!['#', '.', '.', '#'], 2), grid);
+    }
+}
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use crate::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(&self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+// How positions outside the grid are treated: `Bounded` has none, while
+// `Wrapping` takes coordinates modulo rows and cols, making the grid a torus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Topology {
+    #[default]
+    Bounded,
+    Wrapping,
+}
+
+impl<T> Grid<T> {
+    // `None` only for an empty grid.
+    pub fn wrap(&self, row: i64, col: i64) -> Option<(usize, usize)> {
+        if self.rows() == 0 || self.cols() == 0 {
+            return None;
+        }
+
+        Some((
+            row.rem_euclid(self.rows() as i64) as usize,
+            col.rem_euclid(self.cols() as i64) as usize,
+        ))
+    }
+
+    pub fn get_wrapped(&self, row: i64, col: i64) -> Option<&T> {
+        self.wrap(row, col).and_then(|(r, c)| self.get_ref(r, c))
+    }
+
+    pub fn step(
+        &self,
+        (row, col): (usize, usize),
+        (dr, dc): (isize, isize),
+        topology: Topology,
+    ) -> Option<(usize, usize)> {
+        match topology {
+            Topology::Bounded => {
+                let r = row.checked_add_signed(dr)?;
+                let c = col.checked_add_signed(dc)?;
+                (r < self.rows() && c < self.cols()).then_some((r, c))
+            }
+            Topology::Wrapping => self.wrap(row as i64 + dr as i64, col as i64 + dc as i64),
+        }
+    }
+
+    // On wrapping grids narrower than three cells the same neighbour can be
+    // reached through more than one offset and is yielded once per offset.
+    pub fn neighbours(
+        &self,
+        pos: (usize, usize),
+        connectivity: Connectivity,
+        topology: Topology,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&delta| self.step(pos, delta, topology))
+    }
+
+    // Steps from `start` to every reachable cell, moving only onto cells for
+    // which `passable` holds.
+    pub fn bfs_distances(
+        &self,
+        start: (usize, usize),
+        connectivity: Connectivity,
+        topology: Topology,
+        passable: impl Fn(&T) -> bool,
+    ) -> Grid<Option<usize>> {
+        let mut distances = Grid::fill(self.rows(), self.cols(), None);
+        if distances.set(start.0, start.1, Some(0)).is_none() {
+            return distances;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        while let Some(pos) = queue.pop_front() {
+            let next = distances[pos].map(|d| d + 1);
+            for neighbour in self.neighbours(pos, connectivity, topology) {
+                if distances[neighbour].is_none() && passable(&self[neighbour]) {
+                    distances[neighbour] = next;
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        distances
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input_helpers::get_raw_grid;
+
+    #[test]
+    fn test_wrap() {
+        let grid = Grid::new(vec![1, 2, 3, 4, 5, 6], 2);
+
+        assert_eq!(Some((1, 2)), grid.wrap(-1, -1));
+        assert_eq!(Some((0, 0)), grid.wrap(4, 9));
+        assert_eq!(Some(&4), grid.get_wrapped(-3, 3));
+        assert_eq!(None, Grid::<u8>::new(vec![], 0).wrap(0, 0));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::fill(3, 4, 0u8);
+
+        let bounded: Vec<_> = grid
+            .neighbours((0, 0), Connectivity::Four, Topology::Bounded)
+            .collect();
+        assert_eq!(vec![(0, 1), (1, 0)], bounded);
+
+        let wrapping: Vec<_> = grid
+            .neighbours((0, 0), Connectivity::Four, Topology::Wrapping)
+            .collect();
+        assert_eq!(vec![(2, 0), (0, 3), (0, 1), (1, 0)], wrapping);
+
+        assert_eq!(
+            8,
+            grid.neighbours((0, 3), Connectivity::Eight, Topology::Wrapping)
+                .count()
+        );
+    }
+
+    #[test]
+    fn test_bfs_wraps_around() {
+        let grid = get_raw_grid("S.#.\n..#.\n..#.").unwrap();
+
+        let bounded =
+            grid.bfs_distances((0, 0), Connectivity::Four, Topology::Bounded, |&c| c != '#');
+        assert_eq!(None, bounded[(0, 3)]);
+
+        let wrapping = grid.bfs_distances((0, 0), Connectivity::Four, Topology::Wrapping, |&c| {
+            c != '#'
+        });
+        assert_eq!(Some(1), wrapping[(0, 3)]);
+        assert_eq!(Some(2), wrapping[(2, 3)]);
+        assert_eq!(None, wrapping[(1, 2)]);
+    }
+}
@@ -67,3 +67,80 @@ impl UnionFind {
       roots.len()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    use super::*;
+
+    // Every element labelled with its part, relabelling a whole part on union
+    struct Naive {
+        labels: Vec<usize>,
+    }
+
+    impl Naive {
+        fn union(&mut self, a: usize, b: usize) -> bool {
+            let (la, lb) = (self.labels[a], self.labels[b]);
+            for label in &mut self.labels {
+                if *label == lb {
+                    *label = la;
+                }
+            }
+
+            la != lb
+        }
+
+        fn part_size(&self, i: usize) -> usize {
+            self.labels.iter().filter(|&&label| label == self.labels[i]).count()
+        }
+    }
+
+    #[test]
+    fn test_union() {
+        let mut uf = UnionFind::new(5);
+
+        assert!(uf.union(0, 1));
+        assert!(uf.union(3, 1));
+        assert!(!uf.union(0, 3));
+        assert_eq!(3, uf.part_size(3));
+        assert_eq!(3, uf.num_parts());
+        assert_eq!(uf.find(0), uf.find(3));
+        assert_ne!(uf.find(0), uf.find(4));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_naive(
+            n in 1..30usize,
+            unions in vec((0..30usize, 0..30usize), 0..40),
+        ) {
+            let mut uf = UnionFind::new(n);
+            let mut naive = Naive { labels: (0..n).collect() };
+
+            for (a, b) in unions {
+                let (a, b) = (a % n, b % n);
+                prop_assert_eq!(naive.union(a, b), uf.union(a, b));
+            }
+
+            for i in 0..n {
+                prop_assert_eq!(naive.part_size(i), uf.part_size(i));
+                for j in 0..n {
+                    let same = naive.labels[i] == naive.labels[j];
+                    prop_assert_eq!(same, uf.find(i) == uf.find(j));
+                }
+            }
+
+            let mut sizes = uf.all_sizes();
+            sizes.sort_unstable();
+            let mut naive_sizes: Vec<usize> = (0..n)
+                .filter(|&i| naive.labels[i] == i)
+                .map(|i| naive.part_size(i))
+                .collect();
+            naive_sizes.sort_unstable();
+            prop_assert_eq!(naive_sizes.len(), uf.num_parts());
+            prop_assert_eq!(naive_sizes, sizes);
+        }
+    }
+}